@@ -1,9 +1,10 @@
 use proc_macro2::Ident;
 use quote::format_ident;
-use syn::{LitInt, LitStr, Type, Visibility};
+use syn::ext::IdentExt;
+use syn::{LitInt, LitStr, Path, Type, Visibility};
 
 use crate::analyze::vis_to_display;
-use crate::parse::annotations::{Default, Index, OnAction};
+use crate::parse::annotations::{Default, Index, OnAction, OrderBy};
 use crate::parse::model::{ModelAnnotations, ModelFieldAnnotations, ParsedField, ParsedModel};
 use crate::utils::to_db_name;
 
@@ -18,6 +19,8 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
                 query,
                 update,
                 delete,
+                order_by,
+                migration_group,
             },
         fields,
     } = parsed;
@@ -29,6 +32,18 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
         errors.push(darling::Error::custom("Table names can't contain a double underscore. If you need to name your model like this, consider using `#[rorm(rename = \"...\")]`.").with_span(&table));
     }
 
+    // Check migration group to be usable as directory name
+    if let Some(group) = migration_group.as_ref() {
+        let value = group.value();
+        if value.is_empty()
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            errors.push(darling::Error::custom("Migration groups may only contain ascii letters, digits, underscores and dashes.").with_span(group));
+        }
+    }
+
     // Analyze fields
     let mut analyzed_fields = Vec::with_capacity(
         /* assuming most fields won't be ignored */
@@ -53,6 +68,7 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
                     rename,
                     //ignore,
                     default,
+                    default_fn,
                     max_length,
                     index,
                 },
@@ -86,6 +102,24 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
             auto_increment = true;
         }
 
+        // Check default and default_fn not to be combined
+        if let (Some(_), Some(default_fn)) = (&default, &default_fn) {
+            errors.push(
+                darling::Error::custom(
+                    "`#[rorm(default = ..)]` and `#[rorm(default_fn = ..)]` can't be combined. Please remove one of them.",
+                )
+                    .with_span(default_fn),
+            );
+        }
+        if let (true, Some(default_fn)) = (auto_create_time || auto_update_time, &default_fn) {
+            errors.push(
+                darling::Error::custom(
+                    "`#[rorm(default_fn = ..)]` can't be combined with `#[rorm(auto_create_time)]` or `#[rorm(auto_update_time)]`. Please remove one of them.",
+                )
+                    .with_span(default_fn),
+            );
+        }
+
         analyzed_fields.push(AnalyzedField {
             vis,
             unit: format_ident!("__{}_{}", model_ident, ident),
@@ -101,6 +135,7 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
                 on_delete,
                 on_update,
                 default,
+                default_fn,
                 max_length,
                 index,
             },
@@ -134,12 +169,43 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
         )),
     }
 
+    // Resolve the default ordering's field names
+    let mut default_ordering = Vec::new();
+    if let Some(OrderBy(order_by)) = order_by {
+        default_ordering.reserve_exact(order_by.len());
+        for name in order_by {
+            let value = name.value();
+            let (field_name, descending) = match value.strip_prefix('-') {
+                Some(field_name) => (field_name, true),
+                None => (value.as_str(), false),
+            };
+            match analyzed_fields
+                .iter()
+                .position(|field| field.ident.unraw() == field_name)
+            {
+                Some(index) if is_back_ref(&analyzed_fields[index].ty) => errors.push(
+                    darling::Error::custom(format!(
+                        "Can't order by `{field_name}`, because it is a BackRef"
+                    ))
+                    .with_span(&name),
+                ),
+                Some(index) => default_ordering.push((index, descending)),
+                None => errors.push(
+                    darling::Error::custom(format!("Model has no field `{field_name}`"))
+                        .with_span(&name),
+                ),
+            }
+        }
+    }
+
     errors.finish_with(AnalyzedModel {
         vis: vis.clone(),
         ident,
         table,
         fields: analyzed_fields,
         primary_key,
+        default_ordering,
+        migration_group,
         insert,
         query,
         update,
@@ -147,6 +213,18 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
     })
 }
 
+/// Syntactically check whether a field's type is a [`BackRef`]
+///
+/// This can't see through type aliases.
+fn is_back_ref(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => {
+            matches!(path.path.segments.last(), Some(segment) if segment.ident == "BackRef")
+        }
+        _ => false,
+    }
+}
+
 pub struct AnalyzedModel {
     pub vis: Visibility,
     pub ident: Ident,
@@ -154,6 +232,9 @@ pub struct AnalyzedModel {
     pub fields: Vec<AnalyzedField>,
     /// the primary key's index
     pub primary_key: usize,
+    /// the fields' indexes to order by and whether to order descending
+    pub default_ordering: Vec<(usize, bool)>,
+    pub migration_group: Option<LitStr>,
 
     pub insert: Option<Visibility>,
     pub query: Option<Visibility>,
@@ -179,6 +260,7 @@ pub struct AnalyzedModelFieldAnnotations {
     pub on_delete: Option<OnAction>,
     pub on_update: Option<OnAction>,
     pub default: Option<Default>,
+    pub default_fn: Option<Path>,
     pub max_length: Option<LitInt>,
     pub index: Option<Index>,
 }
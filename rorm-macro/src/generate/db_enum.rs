@@ -7,14 +7,39 @@ pub fn generate_db_enum(parsed: &ParsedDbEnum) -> TokenStream {
     let ParsedDbEnum {
         vis,
         ident,
+        repr,
         variants,
     } = parsed;
     let decoder = format_ident!("__{ident}_Decoder");
+    let values: Vec<_> = variants.iter().map(|variant| &variant.value).collect();
+    let variants: Vec<_> = variants.iter().map(|variant| &variant.ident).collect();
+
+    if let Some(repr) = repr {
+        return quote! {
+            ::rorm::impl_field_type!(
+                #ident,
+                #repr,
+                into: |value: &#ident| -> #repr {
+                    match value {
+                        #(
+                            #ident::#variants => #values,
+                        )*
+                    }
+                },
+                from: |value: #repr| match value {
+                    #(
+                        #values => Ok(#ident::#variants),
+                    )*
+                    _ => Err(::rorm::Error::DecodeError(format!("Invalid value '{}' for enum '{}'", value, stringify!(#ident)))),
+                },
+            );
+        };
+    }
 
     quote! {
         const _: () = {
             const CHOICES: &'static [&'static str] = &[
-                #(stringify!(#variants)),*
+                #(#values),*
             ];
 
             impl ::rorm::fields::traits::FieldType for #ident {
@@ -23,7 +48,7 @@ pub fn generate_db_enum(parsed: &ParsedDbEnum) -> TokenStream {
                 fn into_values(self) -> Self::Columns<::rorm::conditions::Value<'static>> {
                     [::rorm::conditions::Value::Choice(::std::borrow::Cow::Borrowed(match self {
                         #(
-                            Self::#variants => stringify!(#variants),
+                            Self::#variants => #values,
                         )*
                     }))]
                 }
@@ -31,7 +56,7 @@ pub fn generate_db_enum(parsed: &ParsedDbEnum) -> TokenStream {
                 fn as_values(&self) -> Self::Columns<::rorm::conditions::Value<'_>> {
                     [::rorm::conditions::Value::Choice(::std::borrow::Cow::Borrowed(match self {
                         #(
-                            Self::#variants => stringify!(#variants),
+                            Self::#variants => #values,
                         )*
                     }))]
                 }
@@ -63,7 +88,7 @@ pub fn generate_db_enum(parsed: &ParsedDbEnum) -> TokenStream {
                     let value: String = value.0;
                     match value.as_str() {
                         #(
-                            stringify!(#variants) => Ok(#ident::#variants),
+                            #values => Ok(#ident::#variants),
                         )*
                         _ => Err(::rorm::Error::DecodeError(format!("Invalid value '{}' for enum '{}'", value, stringify!(#ident)))),
                     }
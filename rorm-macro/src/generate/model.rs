@@ -17,6 +17,8 @@ pub fn generate_model(model: &AnalyzedModel) -> TokenStream {
         table,
         fields,
         primary_key,
+        default_ordering,
+        migration_group,
         insert,
         query,
         update,
@@ -85,6 +87,86 @@ pub fn generate_model(model: &AnalyzedModel) -> TokenStream {
         quote! { ::rorm::model::Unrestricted }
     };
 
+    let default_ordering = if default_ordering.is_empty() {
+        None
+    } else {
+        let entries = default_ordering.iter().map(|(index, descending)| {
+            let field_struct = &fields[*index].unit;
+            let ordering = if *descending {
+                quote! { Desc }
+            } else {
+                quote! { Asc }
+            };
+            quote! {
+                ::rorm::db::sql::ordering::OrderByEntry {
+                    ordering: ::rorm::db::sql::ordering::Ordering::#ordering,
+                    table_name: Some(#table),
+                    column_name: <#field_struct as ::rorm::internal::field::Field>::NAME,
+                }
+            }
+        });
+        Some(quote! {
+            const DEFAULT_ORDERING: &'static [::rorm::db::sql::ordering::OrderByEntry<'static>] = &[#(#entries,)*];
+        })
+    };
+
+    let default_fn_fields: Vec<_> = fields
+        .iter()
+        .filter_map(|field| {
+            let function = if let Some(path) = field.annos.default_fn.as_ref() {
+                quote! { #path }
+            } else if field.annos.auto_create_time || field.annos.auto_update_time {
+                let ty = &field.ty;
+                quote! { <#ty as ::rorm::fields::traits::AutoTime>::now }
+            } else {
+                return None;
+            };
+            Some((&field.unit, &field.ty, function))
+        })
+        .collect();
+    let default_fn = if default_fn_fields.is_empty() {
+        None
+    } else {
+        let field_structs_1 = default_fn_fields.iter().map(|(unit, _, _)| unit);
+        let field_structs_2 = field_structs_1.clone();
+        let field_types = default_fn_fields.iter().map(|(_, ty, _)| ty);
+        let functions = default_fn_fields.iter().map(|(_, _, function)| function);
+        Some(quote! {
+            const DEFAULT_FN_COLUMNS: &'static [&'static str] = &[#(
+                <#field_structs_1 as ::rorm::internal::field::Field>::NAME,
+            )*];
+
+            fn default_fn_value(column: &str) -> Option<::rorm::conditions::Value<'static>> {
+                #(
+                    if column == <#field_structs_2 as ::rorm::internal::field::Field>::NAME {
+                        let [value] = <#field_types as ::rorm::fields::traits::FieldType>::into_values(#functions());
+                        return Some(value);
+                    }
+                )*
+                None
+            }
+        })
+    };
+
+    let auto_update_time_fields: Vec<_> = fields
+        .iter()
+        .filter(|field| field.annos.auto_update_time)
+        .map(|field| &field.unit)
+        .collect();
+    let auto_update_time = (!auto_update_time_fields.is_empty()).then(|| {
+        quote! {
+            const AUTO_UPDATE_TIME_COLUMNS: &'static [&'static str] = &[#(
+                <#auto_update_time_fields as ::rorm::internal::field::Field>::NAME,
+            )*];
+        }
+    });
+
+    let migration_group = migration_group.as_ref().map(|group| {
+        quote! {
+            const MIGRATION_GROUP: Option<&'static str> = Some(#group);
+        }
+    });
+
     let mut tokens = quote! {
         #field_declarations
         #fields_struct
@@ -99,6 +181,14 @@ pub fn generate_model(model: &AnalyzedModel) -> TokenStream {
 
                 const TABLE: &'static str = #table;
 
+                #default_ordering
+
+                #migration_group
+
+                #default_fn
+
+                #auto_update_time
+
                 fn get_imr() -> ::rorm::imr::Model {
                     use ::rorm::internal::field::Field;
                     let mut fields = Vec::new();
@@ -146,7 +236,10 @@ pub fn generate_model(model: &AnalyzedModel) -> TokenStream {
 
             #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
             #[linkme(crate = ::rorm::linkme)]
-            static __get_imr: fn() -> ::rorm::imr::Model = <#ident as ::rorm::model::Model>::get_imr;
+            static __get_imr: (Option<&'static str>, fn() -> ::rorm::imr::Model) = (
+                <#ident as ::rorm::model::Model>::MIGRATION_GROUP,
+                <#ident as ::rorm::model::Model>::get_imr,
+            );
 
             #impl_patch
 
@@ -262,6 +355,7 @@ fn generate_field_annotations(annos: &AnalyzedModelFieldAnnotations) -> TokenStr
         on_delete,
         on_update,
         default,
+        default_fn: _, // Not part of the annotations since it is handled in rust
         max_length,
         index,
     } = annos;
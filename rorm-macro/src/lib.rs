@@ -19,7 +19,7 @@ mod generate;
 mod parse;
 mod utils;
 
-#[proc_macro_derive(DbEnum)]
+#[proc_macro_derive(DbEnum, attributes(rorm))]
 pub fn derive_db_enum(input: TokenStream) -> TokenStream {
     match parse_db_enum(input.into()) {
         Ok(model) => generate_db_enum(&model),
@@ -58,6 +58,10 @@ pub fn rorm_main(args: TokenStream, item: TokenStream) -> TokenStream {
             fn main() -> Result<(), String> {
                 let mut file = ::std::fs::File::create(".models.json").map_err(|err| err.to_string())?;
                 ::rorm::write_models(&mut file)?;
+                for group in ::rorm::migration_groups() {
+                    let mut file = ::std::fs::File::create(format!(".models.{group}.json")).map_err(|err| err.to_string())?;
+                    ::rorm::write_models_of_group(&mut file, Some(group))?;
+                }
                 return Ok(());
             }
             #[cfg(not(feature = #feature))]
@@ -47,6 +47,28 @@ impl FromMeta for OnAction {
     }
 }
 
+/// `#[rorm(as = "..")]`
+#[derive(Debug)]
+pub struct IntRepr(pub Ident);
+impl FromMeta for IntRepr {
+    fn from_value(lit: &Lit) -> darling::Result<Self> {
+        static OPTIONS: [&str; 3] = ["i16", "i32", "i64"];
+        (match lit {
+            Lit::Str(string) => {
+                let string = string.value();
+                let value = string.as_str();
+                if OPTIONS.contains(&value) {
+                    Ok(IntRepr(Ident::new(value, lit.span())))
+                } else {
+                    Err(Error::unknown_field_with_alts(value, &OPTIONS))
+                }
+            }
+            _ => Err(Error::unexpected_lit_type(lit)),
+        })
+        .map_err(|e| e.with_span(lit))
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct Index(pub Option<NamedIndex>);
 impl FromMeta for Index {
@@ -68,3 +90,99 @@ pub struct NamedIndex {
     pub name: LitStr,
     pub priority: Option<LitInt>,
 }
+
+/// `#[rorm(order_by("field", "-other_field"))]`
+///
+/// A list of field names, each optionally prefixed by `-` for descending order.
+#[derive(Default, Debug)]
+pub struct OrderBy(pub Vec<LitStr>);
+impl FromMeta for OrderBy {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut errors = Error::accumulator();
+        let mut fields = Vec::with_capacity(items.len());
+        for item in items {
+            match item {
+                NestedMeta::Lit(Lit::Str(string)) => fields.push(string.clone()),
+                NestedMeta::Lit(lit) => errors.push(Error::unexpected_lit_type(lit).with_span(lit)),
+                NestedMeta::Meta(meta) => {
+                    errors.push(Error::custom("Expected a string literal").with_span(meta))
+                }
+            }
+        }
+        errors.finish_with(OrderBy(fields))
+    }
+}
+
+/// `#[rorm(rename_all = "..")]`
+///
+/// Converts a variant's name (which is expected to be `PascalCase`) using the same rules as serde.
+#[derive(Copy, Clone, Debug)]
+pub enum RenameAll {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+impl RenameAll {
+    const OPTIONS: [(&'static str, Self); 8] = [
+        ("lowercase", Self::Lower),
+        ("UPPERCASE", Self::Upper),
+        ("PascalCase", Self::Pascal),
+        ("camelCase", Self::Camel),
+        ("snake_case", Self::Snake),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnake),
+        ("kebab-case", Self::Kebab),
+        ("SCREAMING-KEBAB-CASE", Self::ScreamingKebab),
+    ];
+
+    /// Apply the rule to a variant's name
+    pub fn apply(self, variant: &str) -> String {
+        match self {
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Pascal => variant.to_string(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_lowercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+            Self::Snake => {
+                let mut snake = String::new();
+                for (i, char) in variant.char_indices() {
+                    if i > 0 && char.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(char.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake.apply(variant).replace('_', "-"),
+        }
+    }
+}
+impl FromMeta for RenameAll {
+    fn from_value(lit: &Lit) -> darling::Result<Self> {
+        (match lit {
+            Lit::Str(string) => {
+                let string = string.value();
+                let value = string.as_str();
+                Self::OPTIONS
+                    .iter()
+                    .find_map(|(name, rule)| (*name == value).then_some(*rule))
+                    .ok_or_else(|| {
+                        Error::unknown_field_with_alts(value, &Self::OPTIONS.map(|(name, _)| name))
+                    })
+            }
+            _ => Err(Error::unexpected_lit_type(lit)),
+        })
+        .map_err(|e| e.with_span(lit))
+    }
+}
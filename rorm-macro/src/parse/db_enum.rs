@@ -1,8 +1,8 @@
 use darling::FromAttributes;
 use proc_macro2::{Ident, TokenStream};
-use syn::{ItemEnum, Variant, Visibility};
+use syn::{ItemEnum, Lit, LitInt, LitStr, Variant, Visibility};
 
-use crate::parse::annotations::NoAnnotations;
+use crate::parse::annotations::{IntRepr, RenameAll};
 
 pub fn parse_db_enum(tokens: TokenStream) -> darling::Result<ParsedDbEnum> {
     let ItemEnum {
@@ -16,8 +16,17 @@ pub fn parse_db_enum(tokens: TokenStream) -> darling::Result<ParsedDbEnum> {
     } = syn::parse2(tokens)?;
     let mut errors = darling::Error::accumulator();
 
-    // check absence of #[rorm(..)] attributes
-    let _ = errors.handle(NoAnnotations::from_attributes(&attrs));
+    // parse enum annotations
+    let annos = errors
+        .handle(DbEnumAnnotations::from_attributes(&attrs))
+        .unwrap_or_default();
+    let repr = annos.repr.map(|IntRepr(repr)| repr);
+    if let (Some(repr), Some(_)) = (&repr, &annos.rename_all) {
+        errors.push(
+            darling::Error::custom("rename_all can't be used on an integer backed enum")
+                .with_span(repr),
+        );
+    }
 
     // check absence of generics
     if generics.lt_token.is_some() {
@@ -37,8 +46,10 @@ pub fn parse_db_enum(tokens: TokenStream) -> darling::Result<ParsedDbEnum> {
             discriminant: _, // TODO maybe warn, that they aren't used?
         } = variant;
 
-        // check absence of #[rorm(..)] attributes
-        let _ = errors.handle(NoAnnotations::from_attributes(&attrs));
+        // parse variant annotations
+        let variant_annos = errors
+            .handle(DbEnumVariantAnnotations::from_attributes(&attrs))
+            .unwrap_or_default();
 
         // check absence of fields
         if !fields.is_empty() {
@@ -48,12 +59,73 @@ pub fn parse_db_enum(tokens: TokenStream) -> darling::Result<ParsedDbEnum> {
             );
         }
 
-        parsed_variants.push(ident);
+        // determine the value stored in the database
+        let value = if repr.is_some() {
+            if let Some(rename) = &variant_annos.rename {
+                errors.push(
+                    darling::Error::custom(
+                        "rename can't be used on an integer backed enum, use value instead",
+                    )
+                    .with_span(rename),
+                );
+            }
+            let Some(value) = variant_annos.value else {
+                errors.push(
+                    darling::Error::custom(
+                        "Variants of an integer backed enum require #[rorm(value = ..)]",
+                    )
+                    .with_span(&ident),
+                );
+                continue;
+            };
+            Lit::Int(value)
+        } else {
+            if let Some(value) = &variant_annos.value {
+                errors.push(
+                    darling::Error::custom(
+                        "value requires an integer backed enum i.e. #[rorm(as = \"i16\")]",
+                    )
+                    .with_span(value),
+                );
+            }
+            Lit::Str(if let Some(rename) = variant_annos.rename {
+                rename
+            } else if let Some(rename_all) = annos.rename_all {
+                LitStr::new(&rename_all.apply(&ident.to_string()), ident.span())
+            } else {
+                LitStr::new(&ident.to_string(), ident.span())
+            })
+        };
+
+        // check uniqueness of values
+        let value_str = match &value {
+            Lit::Int(int) => int.base10_digits().to_string(),
+            Lit::Str(string) => string.value(),
+            _ => unreachable!("values are either integers or strings"),
+        };
+        if parsed_variants
+            .iter()
+            .any(|other: &ParsedDbEnumVariant| match &other.value {
+                Lit::Int(int) => int.base10_digits() == value_str,
+                Lit::Str(string) => string.value() == value_str,
+                _ => false,
+            })
+        {
+            errors.push(
+                darling::Error::custom(format!(
+                    "The value \"{value_str}\" is used by more than one variant",
+                ))
+                .with_span(&value),
+            );
+        }
+
+        parsed_variants.push(ParsedDbEnumVariant { ident, value });
     }
 
     errors.finish_with(ParsedDbEnum {
         vis,
         ident,
+        repr,
         variants: parsed_variants,
     })
 }
@@ -61,5 +133,39 @@ pub fn parse_db_enum(tokens: TokenStream) -> darling::Result<ParsedDbEnum> {
 pub struct ParsedDbEnum {
     pub vis: Visibility,
     pub ident: Ident,
-    pub variants: Vec<Ident>,
+
+    /// The integer type used to store the enum, if it is not stored as string
+    pub repr: Option<Ident>,
+
+    pub variants: Vec<ParsedDbEnumVariant>,
+}
+
+pub struct ParsedDbEnumVariant {
+    pub ident: Ident,
+
+    /// The value stored in the database for this variant
+    ///
+    /// This is either a [`LitStr`] or a [`LitInt`] depending on [`ParsedDbEnum::repr`].
+    pub value: Lit,
+}
+
+#[derive(FromAttributes, Debug, Default)]
+#[darling(attributes(rorm), default)]
+pub struct DbEnumAnnotations {
+    /// `#[rorm(rename_all = "..")]`
+    pub rename_all: Option<RenameAll>,
+
+    /// `#[rorm(as = "..")]`
+    #[darling(rename = "as")]
+    pub repr: Option<IntRepr>,
+}
+
+#[derive(FromAttributes, Debug, Default)]
+#[darling(attributes(rorm), default)]
+pub struct DbEnumVariantAnnotations {
+    /// `#[rorm(rename = "..")]`
+    pub rename: Option<LitStr>,
+
+    /// `#[rorm(value = ..)]`
+    pub value: Option<LitInt>,
 }
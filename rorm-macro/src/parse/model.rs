@@ -1,8 +1,8 @@
 use darling::FromAttributes;
 use proc_macro2::{Ident, TokenStream};
-use syn::{parse2, Field, ItemStruct, LitInt, LitStr, Type, Visibility};
+use syn::{parse2, Field, ItemStruct, LitInt, LitStr, Path, Type, Visibility};
 
-use crate::parse::annotations::{Default, Index, OnAction};
+use crate::parse::annotations::{Default, Index, OnAction, OrderBy};
 use crate::parse::{check_non_generic, get_fields_named};
 
 pub fn parse_model(tokens: TokenStream) -> darling::Result<ParsedModel> {
@@ -73,6 +73,12 @@ pub struct ModelAnnotations {
     pub query: Option<Visibility>,
     pub update: Option<Visibility>,
     pub delete: Option<Visibility>,
+
+    /// `#[rorm(order_by(..))]`
+    pub order_by: Option<OrderBy>,
+
+    /// `#[rorm(migration_group = "..")]`
+    pub migration_group: Option<LitStr>,
 }
 
 pub struct ParsedField {
@@ -127,6 +133,12 @@ pub struct ModelFieldAnnotations {
     /// TODO: Figure out how to check the literal's type is compatible with the annotated field's type
     pub default: Option<Default>,
 
+    /// Parse the `#[rorm(default_fn = "..")]` annotation.
+    ///
+    /// It accepts a string literal containing the path to a function
+    /// which is called to produce the field's value, when an inserted patch doesn't contain it.
+    pub default_fn: Option<Path>,
+
     /// Parse the `#[rorm(max_length = ..)]` annotation.
     ///
     /// It accepts a single integer literal as argument.
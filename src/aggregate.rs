@@ -5,7 +5,8 @@ use std::marker::PhantomData;
 use rorm_db::row::DecodeOwned;
 use rorm_db::sql::aggregation::SelectAggregator;
 
-use crate::internal::field::{FieldProxy, SingleColumnField};
+use crate::internal::const_concat::ConstString;
+use crate::internal::field::{Field, FieldProxy, SingleColumnField};
 use crate::internal::relation_path::Path;
 
 /// A function which can be used in aggregation.
@@ -109,3 +110,26 @@ pub struct AggregatedColumn<A, F, P> {
     field: PhantomData<F>,
     path: PhantomData<P>,
 }
+impl<A: AggregationFunc, F: Field, P: Path> AggregatedColumn<A, F, P> {
+    /// The alias the aggregation is selected as
+    ///
+    /// It is used by [`QueryBuilder::order_by_aggregation`](crate::crud::query::QueryBuilder::order_by_aggregation)
+    /// to reference the aggregation:
+    /// ```
+    /// # use rorm::{field, Model};
+    /// # use rorm::aggregate::{AggregatedColumn, Count};
+    /// # use rorm::internal::query_context::QueryContext;
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64 }
+    /// type UserCount = AggregatedColumn<Count, field!(User::F.id), User>;
+    /// assert_eq!(UserCount::ALIAS, "user__id___count");
+    ///
+    /// let mut ctx = QueryContext::new();
+    /// assert!(!ctx.is_selected(UserCount::ALIAS));
+    ///
+    /// let (_, alias) = ctx.select_aggregation::<Count, field!(User::F.id), User>();
+    /// assert_eq!(alias, UserCount::ALIAS);
+    /// assert!(ctx.is_selected(UserCount::ALIAS));
+    /// ```
+    pub const ALIAS: &'static str =
+        ConstString::select_alias(&[P::ALIAS, "__", F::NAME, "___", A::NAME]).as_str();
+}
@@ -6,7 +6,7 @@
 //!
 //! Where static and dynamic mean whether the collection's size is known at compile time.
 
-use rorm_db::sql::conditional;
+use rorm_db::sql::{conditional, value};
 
 use super::Condition;
 use crate::internal::query_context::QueryContext;
@@ -32,6 +32,34 @@ pub enum CollectionOperator {
 /// - All conditions have to be of the same type.
 ///     This can be mitigated by erasing their type using [Condition::boxed].
 ///     In this case use [BoxedCondition<'a>](super::BoxedCondition) for the generic variable `T`.
+///
+/// ## Empty collections
+/// An empty collection is valid:
+/// joined by AND it is always true and joined by OR it is always false.
+///
+/// ## Example
+/// Building a condition from an optional set of filters:
+/// ```no_run
+/// # use rorm::Model;
+/// # use rorm::conditions::{BoxedCondition, Condition, DynamicCollection};
+/// # use rorm::internal::field::access::FieldAccess;
+/// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String, age: i16 }
+/// struct UserFilter {
+///     name: Option<String>,
+///     min_age: Option<i16>,
+/// }
+///
+/// fn build_condition(filter: UserFilter) -> DynamicCollection<BoxedCondition<'static>> {
+///     let mut conditions = Vec::new();
+///     if let Some(name) = filter.name {
+///         conditions.push(User::F.name.equals(name).boxed());
+///     }
+///     if let Some(min_age) = filter.min_age {
+///         conditions.push(User::F.age.greater_equals(min_age).boxed());
+///     }
+///     DynamicCollection::and(conditions)
+/// }
+/// ```
 #[derive(Clone)]
 pub struct DynamicCollection<T> {
     /// Operator used for joining, i.e. `and` or `or`
@@ -66,6 +94,12 @@ impl<'a, A: Condition<'a>> Condition<'a> for DynamicCollection<A> {
     }
 
     fn as_sql(&self, context: &QueryContext) -> conditional::Condition {
+        if self.vector.is_empty() {
+            return conditional::Condition::Value(value::Value::Bool(match self.operator {
+                CollectionOperator::And => true,
+                CollectionOperator::Or => false,
+            }));
+        }
         (match self.operator {
             CollectionOperator::And => conditional::Condition::Conjunction,
             CollectionOperator::Or => conditional::Condition::Disjunction,
@@ -156,7 +190,7 @@ impl_static_collection!(H, G, F, E, D, C, B, A);
 #[macro_export]
 macro_rules! create_collection {
     ($method:ident, $H:expr, $G:expr, $F:expr, $E:expr, $D:expr, $C:expr, $B:expr, $A:expr, $($other:expr),+ $(,)?) => {
-        $crate::conditions::collections::DynamicCollection::ident(vec![
+        $crate::conditions::collections::DynamicCollection::$method(vec![
             $H.boxed(),
             $G.boxed(),
             $F.boxed(),
@@ -194,3 +228,186 @@ macro_rules! and {
         $crate::create_collection!(and, $($condition),+);
     };
 }
+
+/// Alias for [`and!`](crate::and): the condition is true if all of its arguments are.
+///
+/// Unlike [`and!`](crate::and) it can be called without arguments,
+/// which produces an empty [`DynamicCollection`] which is always true:
+/// ```
+/// use rorm::{all, FieldAccess, Model};
+/// use rorm::conditions::Condition;
+/// use rorm::db::sql::{conditional, value};
+/// use rorm::internal::query_context::QueryContext;
+///
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     admin: bool,
+/// }
+///
+/// let context = QueryContext::new();
+/// assert!(matches!(
+///     all!().as_sql(&context),
+///     conditional::Condition::Value(value::Value::Bool(true))
+/// ));
+/// assert!(matches!(
+///     all!(User::F.id.equals(1), User::F.admin.equals(true)).as_sql(&context),
+///     conditional::Condition::Conjunction(conditions) if conditions.len() == 2
+/// ));
+/// ```
+#[macro_export]
+macro_rules! all {
+    () => {
+        $crate::conditions::collections::DynamicCollection::<
+            $crate::conditions::BoxedCondition<'static>,
+        >::and(Vec::new())
+    };
+    ($($condition:expr),+ $(,)?) => {
+        $crate::create_collection!(and, $($condition),+)
+    };
+}
+
+/// Alias for [`or!`](crate::or): the condition is true if any of its arguments is.
+///
+/// Unlike [`or!`](crate::or) it can be called without arguments,
+/// which produces an empty [`DynamicCollection`] which is always false:
+/// ```
+/// use rorm::{any, FieldAccess, Model};
+/// use rorm::conditions::Condition;
+/// use rorm::db::sql::{conditional, value};
+/// use rorm::internal::query_context::QueryContext;
+///
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     admin: bool,
+/// }
+///
+/// let context = QueryContext::new();
+/// assert!(matches!(
+///     any!().as_sql(&context),
+///     conditional::Condition::Value(value::Value::Bool(false))
+/// ));
+/// assert!(matches!(
+///     any!(User::F.id.equals(1), User::F.admin.equals(true)).as_sql(&context),
+///     conditional::Condition::Disjunction(conditions) if conditions.len() == 2
+/// ));
+/// ```
+#[macro_export]
+macro_rules! any {
+    () => {
+        $crate::conditions::collections::DynamicCollection::<
+            $crate::conditions::BoxedCondition<'static>,
+        >::or(Vec::new())
+    };
+    ($($condition:expr),+ $(,)?) => {
+        $crate::create_collection!(or, $($condition),+)
+    };
+}
+
+/// Negation of [`or!`](crate::or): the condition is true if none of its arguments is.
+///
+/// It lowers to a `NOT` around a disjunction.
+/// Called without arguments it negates the always false [`any!()`](crate::any) and is therefore always true.
+/// ```
+/// use rorm::{none, FieldAccess, Model};
+/// use rorm::conditions::Condition;
+/// use rorm::db::sql::{conditional, value};
+/// use rorm::internal::query_context::QueryContext;
+///
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     admin: bool,
+/// }
+///
+/// let context = QueryContext::new();
+/// let condition = none!(User::F.id.equals(1), User::F.admin.equals(true));
+/// let conditional::Condition::UnaryCondition(conditional::UnaryCondition::Not(negated)) =
+///     condition.as_sql(&context)
+/// else {
+///     panic!("Expected a NOT");
+/// };
+/// assert!(matches!(
+///     *negated,
+///     conditional::Condition::Disjunction(conditions) if conditions.len() == 2
+/// ));
+///
+/// let condition = none!();
+/// let conditional::Condition::UnaryCondition(conditional::UnaryCondition::Not(negated)) =
+///     condition.as_sql(&context)
+/// else {
+///     panic!("Expected a NOT");
+/// };
+/// assert!(matches!(
+///     *negated,
+///     conditional::Condition::Value(value::Value::Bool(false))
+/// ));
+/// ```
+#[macro_export]
+macro_rules! none {
+    () => {
+        $crate::conditions::Unary {
+            operator: $crate::conditions::UnaryOperator::Not,
+            fst_arg: $crate::any!(),
+        }
+    };
+    ($($condition:expr),+ $(,)?) => {
+        $crate::conditions::Unary {
+            operator: $crate::conditions::UnaryOperator::Not,
+            fst_arg: $crate::create_collection!(or, $($condition),+),
+        }
+    };
+}
+
+/// Negate a single [Condition]: the condition is true if its argument is false.
+///
+/// The argument's joins are still added to the query.
+/// ```
+/// use rorm::{not, FieldAccess, Model};
+/// use rorm::conditions::Condition;
+/// use rorm::db::sql::conditional;
+/// use rorm::fields::types::ForeignModel;
+/// use rorm::internal::query_context::QueryContext;
+///
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     banned: bool,
+/// }
+///
+/// #[derive(Model)]
+/// struct Post {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     author: ForeignModel<User>,
+/// }
+///
+/// let condition = not!(Post::F.author.banned.equals(true));
+///
+/// let mut context = QueryContext::new();
+/// condition.add_to_context(&mut context);
+/// assert_eq!(context.get_joins().len(), 1);
+/// assert!(matches!(
+///     condition.as_sql(&context),
+///     conditional::Condition::UnaryCondition(conditional::UnaryCondition::Not(_))
+/// ));
+/// ```
+#[macro_export]
+macro_rules! not {
+    ($condition:expr $(,)?) => {
+        $crate::conditions::Unary {
+            operator: $crate::conditions::UnaryOperator::Not,
+            fst_arg: $condition,
+        }
+    };
+}
@@ -89,6 +89,16 @@ impl<'a> Condition<'a> for ArcCondition<'a> {
     }
 }
 
+impl<'a, 'c: 'a, C: Condition<'c> + ?Sized> Condition<'a> for &'a C {
+    fn add_to_context(&self, context: &mut QueryContext) {
+        C::add_to_context(self, context);
+    }
+
+    fn as_sql(&self, context: &QueryContext) -> conditional::Condition<'_> {
+        C::as_sql(self, context)
+    }
+}
+
 /// A value
 ///
 /// However unlike rorm-sql's Value, this does not include an ident.
@@ -320,6 +330,56 @@ impl<'a, A: Condition<'a>, B: Condition<'a>, C: Condition<'a>> Condition<'a> for
     }
 }
 
+/// A check whether an expression is contained in a list
+///
+/// An empty list is valid: `IN` is always false and `NOT IN` is always true.
+#[derive(Clone)]
+pub struct In<A, B> {
+    /// SQL operator to use
+    pub operator: InOperator,
+
+    /// The expression to search for
+    pub fst_arg: A,
+
+    /// The list to search in
+    pub snd_arg: Vec<B>,
+}
+/// An operator checking list membership
+#[derive(Copy, Clone)]
+pub enum InOperator {
+    /// Representation of "{} IN ({}, ...)" in SQL
+    In,
+    /// Representation of "{} NOT IN ({}, ...)" in SQL
+    NotIn,
+}
+impl<'a, A: Condition<'a>, B: Condition<'a>> Condition<'a> for In<A, B> {
+    fn add_to_context(&self, context: &mut QueryContext) {
+        self.fst_arg.add_to_context(context);
+        for arg in self.snd_arg.iter() {
+            arg.add_to_context(context);
+        }
+    }
+
+    fn as_sql(&self, context: &QueryContext) -> conditional::Condition<'_> {
+        if self.snd_arg.is_empty() {
+            return conditional::Condition::Value(value::Value::Bool(match self.operator {
+                InOperator::In => false,
+                InOperator::NotIn => true,
+            }));
+        }
+        conditional::Condition::BinaryCondition((match self.operator {
+            InOperator::In => conditional::BinaryCondition::In,
+            InOperator::NotIn => conditional::BinaryCondition::NotIn,
+        })(Box::new([
+            self.fst_arg.as_sql(context),
+            // rorm-sql expects the list as a disjunction of its items
+            conditional::Condition::Disjunction(
+                self.snd_arg.iter().map(|arg| arg.as_sql(context)).collect(),
+            ),
+        ])))
+    }
+}
+
 /// A unary expression
 #[derive(Copy, Clone)]
 pub struct Unary<A> {
@@ -69,6 +69,37 @@ where
     }
 }
 
+/// A [`Decoder`] which decodes `None` if a check column is `NULL` and delegates to another decoder otherwise
+///
+/// It is used to decode a patch from a `LEFT JOIN`ed table, by checking the table's primary key.
+pub struct OptionalDecoder<D, T> {
+    pub(crate) decoder: D,
+    pub(crate) check: DirectDecoder<Option<T>>,
+}
+impl<D, T> Decoder for OptionalDecoder<D, T>
+where
+    D: Decoder,
+    Option<T>: DecodeOwned,
+{
+    type Result = Option<D::Result>;
+
+    fn by_name(&self, row: &Row) -> Result<Self::Result, Error> {
+        if self.check.by_name(row)?.is_none() {
+            Ok(None)
+        } else {
+            self.decoder.by_name(row).map(Some)
+        }
+    }
+
+    fn by_index(&self, row: &Row) -> Result<Self::Result, Error> {
+        if self.check.by_index(row)?.is_none() {
+            Ok(None)
+        } else {
+            self.decoder.by_index(row).map(Some)
+        }
+    }
+}
+
 macro_rules! decoder {
     ($($index:tt : $S:ident,)+) => {
         impl<$($S: Decoder),+> Decoder for ($($S,)+) {
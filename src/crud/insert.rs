@@ -112,7 +112,11 @@ where
         #[allow(clippy::let_unit_value)]
         let _check = Self::CHECK;
 
-        let values = patch.references();
+        let default_fn_columns = default_fn_columns::<P>();
+        let columns = insert_columns::<P>(&default_fn_columns);
+
+        let mut values = patch.references();
+        push_default_fn_values::<M>(&default_fn_columns, &mut values);
         let values: Vec<_> = values.iter().map(Value::as_sql).collect();
 
         let mut ctx = QueryContext::new();
@@ -124,7 +128,7 @@ where
         let row = database::insert_returning(
             self.executor,
             P::Model::TABLE,
-            P::COLUMNS,
+            &columns,
             &values,
             &returning,
         )
@@ -152,16 +156,20 @@ where
         #[allow(clippy::let_unit_value)]
         let _check = Self::CHECK;
 
+        let default_fn_columns = default_fn_columns::<P>();
+        let columns = insert_columns::<P>(&default_fn_columns);
+
         let mut values: Vec<Value<'p>> = Vec::new();
         for patch in patches {
             match patch.into_patch_cow() {
                 PatchCow::Borrowed(patch) => patch.push_references(&mut values),
                 PatchCow::Owned(patch) => patch.push_values(&mut values),
             }
+            push_default_fn_values::<M>(&default_fn_columns, &mut values);
         }
 
         let values: Vec<_> = values.iter().map(Value::as_sql).collect();
-        let values_slices: Vec<_> = values.chunks(P::COLUMNS.len()).collect();
+        let values_slices: Vec<_> = values.chunks(columns.len()).collect();
 
         let mut ctx = QueryContext::new();
         let decoder = self.selector.select(&mut ctx);
@@ -172,7 +180,7 @@ where
         let rows = database::insert_bulk_returning(
             self.executor,
             M::TABLE,
-            P::COLUMNS,
+            &columns,
             &values_slices,
             &returning,
         )
@@ -194,11 +202,14 @@ where
 {
     /// See [`InsertBuilder::single`]
     pub async fn single<P: Patch<Model = M>>(self, patch: &P) -> Result<(), Error> {
-        let values = patch.references();
+        let default_fn_columns = default_fn_columns::<P>();
+        let columns = insert_columns::<P>(&default_fn_columns);
+
+        let mut values = patch.references();
+        push_default_fn_values::<M>(&default_fn_columns, &mut values);
         let values: Vec<_> = values.iter().map(Value::as_sql).collect();
-        let inserting = P::COLUMNS;
 
-        database::insert(self.executor, M::TABLE, inserting, &values).await
+        database::insert(self.executor, M::TABLE, &columns, &values).await
     }
 
     /// See [`InsertBuilder::bulk`]
@@ -208,22 +219,51 @@ where
         I::Item: IntoPatchCow<'p, Patch = P>,
         P: Patch<Model = M>,
     {
+        let default_fn_columns = default_fn_columns::<P>();
+        let columns = insert_columns::<P>(&default_fn_columns);
+
         let mut values: Vec<Value<'p>> = Vec::new();
         for patch in patches {
             match patch.into_patch_cow() {
                 PatchCow::Borrowed(patch) => patch.push_references(&mut values),
                 PatchCow::Owned(patch) => patch.push_values(&mut values),
             }
+            push_default_fn_values::<M>(&default_fn_columns, &mut values);
         }
 
         let values: Vec<_> = values.iter().map(Value::as_sql).collect();
-        let values_slices: Vec<_> = values.chunks(P::COLUMNS.len()).collect();
-        let inserting = P::COLUMNS;
+        let values_slices: Vec<_> = values.chunks(columns.len()).collect();
 
-        database::insert_bulk(self.executor, M::TABLE, inserting, &values_slices).await
+        database::insert_bulk(self.executor, M::TABLE, &columns, &values_slices).await
     }
 }
 
+/// Get the model's [`DEFAULT_FN_COLUMNS`](Model::DEFAULT_FN_COLUMNS) which are missing from the patch
+fn default_fn_columns<P: Patch>() -> Vec<&'static str> {
+    <P::Model as Model>::DEFAULT_FN_COLUMNS
+        .iter()
+        .copied()
+        .filter(|column| !P::COLUMNS.contains(column))
+        .collect()
+}
+
+/// Get the patch's columns followed by the missing `default_fn_columns`
+fn insert_columns<P: Patch>(default_fn_columns: &[&'static str]) -> Vec<&'static str> {
+    P::COLUMNS
+        .iter()
+        .chain(default_fn_columns)
+        .copied()
+        .collect()
+}
+
+/// Compute and push the values for `default_fn_columns`
+fn push_default_fn_values<M: Model>(default_fn_columns: &[&str], values: &mut Vec<Value>) {
+    values.extend(default_fn_columns.iter().map(|column| {
+        M::default_fn_value(column)
+            .expect("DEFAULT_FN_COLUMNS should only contain columns which have a default_fn_value")
+    }));
+}
+
 /// Create an INSERT query.
 ///
 /// # Basic usage
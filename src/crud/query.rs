@@ -2,20 +2,24 @@
 
 use std::ops::{Range, RangeInclusive, Sub};
 
-use rorm_db::database;
 use rorm_db::error::Error;
 use rorm_db::executor::{All, Executor, One, Optional, Stream};
+use rorm_db::sql::join_table::JoinType;
 use rorm_db::sql::limit_clause::LimitClause;
 use rorm_db::sql::ordering::{OrderByEntry, Ordering};
+use rorm_db::{database, Database};
 
+use crate::aggregate::{AggregatedColumn, AggregationFunc};
 use crate::conditions::Condition;
 use crate::crud::builder::ConditionMarker;
 use crate::crud::decoder::Decoder;
 use crate::crud::selector::Selector;
-use crate::internal::field::{Field, FieldProxy};
+use crate::fields::types::ForeignModelByField;
+use crate::internal::field::as_db_type::AsDbType;
+use crate::internal::field::{Field, FieldProxy, SingleColumnField};
 use crate::internal::query_context::QueryContext;
-use crate::internal::relation_path::Path;
-use crate::model::Model;
+use crate::internal::relation_path::{Path, PathStep};
+use crate::model::{Model, OptionalPatchSelector, PatchSelector};
 use crate::sealed;
 
 /// Builder for select queries
@@ -44,7 +48,7 @@ pub struct QueryBuilder<E, S, C, LO> {
     selector: S,
     condition: C,
     lim_off: LO,
-    ordering: Vec<OrderByEntry<'static>>,
+    ordering: Option<Vec<OrderByEntry<'static>>>,
 }
 
 impl<'ex, E, S> QueryBuilder<E, S, (), ()>
@@ -60,13 +64,43 @@ where
             selector,
             condition: (),
             lim_off: (),
-            ordering: Vec::new(),
+            ordering: None,
         }
     }
 }
 
 impl<E, S, LO> QueryBuilder<E, S, (), LO> {
     /// Add a condition to the query
+    ///
+    /// Conditions can also be passed by reference.
+    /// Together with [`ArcCondition`](crate::conditions::ArcCondition) this allows storing
+    /// a prebuilt filter and reusing it across queries:
+    /// ```no_run
+    /// # use rorm::{Model, Database, query, Error};
+    /// # use rorm::conditions::{ArcCondition, Condition};
+    /// # use rorm::internal::field::access::FieldAccess;
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String, active: bool }
+    /// #[derive(Clone)]
+    /// struct ActiveUsers {
+    ///     filter: ArcCondition<'static>,
+    /// }
+    ///
+    /// impl ActiveUsers {
+    ///     fn new() -> Self {
+    ///         Self {
+    ///             filter: User::F.active.equals(true).arc(),
+    ///         }
+    ///     }
+    ///
+    ///     async fn all(&self, db: &Database) -> Result<Vec<User>, Error> {
+    ///         query!(db, User).condition(&self.filter).all().await
+    ///     }
+    ///
+    ///     async fn first(&self, db: &Database) -> Result<Option<User>, Error> {
+    ///         query!(db, User).condition(&self.filter).optional().await
+    ///     }
+    /// }
+    /// ```
     pub fn condition<'c, C: Condition<'c>>(self, condition: C) -> QueryBuilder<E, S, C, LO> {
         #[rustfmt::skip]
         let QueryBuilder { executor, ctx, selector, lim_off, ordering, .. } = self;
@@ -114,6 +148,17 @@ impl<E, S, C> QueryBuilder<E, S, C, ()> {
         #[rustfmt::skip]
         return QueryBuilder { executor, ctx, selector, condition, lim_off: limit, ordering, };
     }
+
+    /// Restrict the query to a single page
+    ///
+    /// Pages are counted starting with `0`.
+    /// See [`Limit::page`] for how pages beyond `u64::MAX` rows are handled.
+    pub fn paginate(self, page: u64, per_page: u64) -> QueryBuilder<E, S, C, Limit<u64>> {
+        #[rustfmt::skip]
+        let QueryBuilder { executor, ctx, selector, condition, ordering,  .. } = self;
+        #[rustfmt::skip]
+        return QueryBuilder { executor, ctx, selector, condition, lim_off: Limit::page(page, per_page), ordering, };
+    }
 }
 
 impl<E, S, C, LO> QueryBuilder<E, S, C, LO>
@@ -123,17 +168,132 @@ where
     /// Order the query by a field
     ///
     /// You can add multiple orderings from most to least significant.
+    /// They replace the model's [default ordering](Model::DEFAULT_ORDERING).
+    ///
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # use rorm::db::sql::ordering::Ordering;
+    /// #[derive(Model)]
+    /// #[rorm(order_by("-created", "name"))]
+    /// struct Article {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     #[rorm(max_length = 255)]
+    ///     name: String,
+    ///
+    ///     created: i64,
+    /// }
+    ///
+    /// # async fn func(db: &Database) {
+    /// // Ordered by `created` descending, then by `name`
+    /// let newest = query!(db, Article).all().await.unwrap();
+    ///
+    /// // Only ordered by `name`
+    /// let alphabetical = query!(db, Article)
+    ///     .order_by(Article::F.name, Ordering::Asc)
+    ///     .all()
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// // Not ordered at all
+    /// let unordered = query!(db, Article).no_order().all().await.unwrap();
+    ///
+    /// // Aggregations ignore the default ordering
+    /// let (count,) = query!(db, (Article::F.id.count(),)).one().await.unwrap();
+    /// # }
+    /// ```
     pub fn order_by<F, P>(mut self, _field: FieldProxy<F, P>, order: Ordering) -> Self
     where
         F: Field,
         P: Path<Origin = S::Model>,
     {
         P::add_to_context(&mut self.ctx);
-        self.ordering.push(OrderByEntry {
-            ordering: order,
-            table_name: Some(P::ALIAS),
-            column_name: F::NAME,
-        });
+        self.ordering
+            .get_or_insert_with(Vec::new)
+            .push(OrderByEntry {
+                ordering: order,
+                table_name: Some(P::ALIAS),
+                column_name: F::NAME,
+            });
+        self
+    }
+
+    /// Use a `LEFT JOIN` instead of a normal (inner) `JOIN` to join a relation
+    ///
+    /// The relation is specified by the [`ForeignModel`](crate::fields::types::ForeignModel)
+    /// or [`BackRef`](crate::fields::types::BackRef) field it is accessed through.
+    /// Only this single join is affected, joins "behind" it have to be changed separately.
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # use rorm::db::sql::ordering::Ordering;
+    /// # use rorm::fields::types::ForeignModel;
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String }
+    /// # #[derive(Model)] struct Post { #[rorm(id)] id: i64, author: Option<ForeignModel<User>> }
+    /// # async fn func(db: &Database) {
+    /// // Orders the posts by their authors' names without dropping posts which have no author
+    /// let posts = query!(db, Post)
+    ///     .left_join(Post::F.author)
+    ///     .order_by(Post::F.author.name, Ordering::Asc)
+    ///     .all()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// Joining a [`BackRef`](crate::fields::types::BackRef) produces one row per related model,
+    /// so the queried model is returned once for each of them
+    /// (and once with `NULL`s if it has none).
+    pub fn left_join<F, P>(mut self, _relation: FieldProxy<F, P>) -> Self
+    where
+        F: Field,
+        P: Path<Origin = S::Model>,
+        PathStep<F, P>: Path,
+    {
+        self.ctx.set_join_type::<PathStep<F, P>>(JoinType::LeftJoin);
+        self
+    }
+
+    /// Order the query by an aggregation
+    ///
+    /// The aggregation is referenced by its alias,
+    /// so it has to be part of the query's selector as well.
+    /// Otherwise executing the query returns an error without sending it to the database:
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # use rorm::db::sql::ordering::Ordering;
+    /// # #[derive(Model)] struct Order { #[rorm(id)] id: i64, amount: i64 }
+    /// # async fn func(db: &Database) {
+    /// let highest = query!(db, (Order::F.amount.max(),))
+    ///     .order_by_aggregation(Order::F.amount.max(), Ordering::Desc)
+    ///     .all()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn order_by_aggregation<A, F, P>(
+        mut self,
+        _aggregation: AggregatedColumn<A, F, P>,
+        order: Ordering,
+    ) -> Self
+    where
+        A: AggregationFunc,
+        F: Field,
+        P: Path<Origin = S::Model>,
+    {
+        self.ordering
+            .get_or_insert_with(Vec::new)
+            .push(OrderByEntry {
+                ordering: order,
+                table_name: None,
+                column_name: AggregatedColumn::<A, F, P>::ALIAS,
+            });
+        self
+    }
+
+    /// Don't order the query, not even by the model's [default ordering](Model::DEFAULT_ORDERING)
+    pub fn no_order(mut self) -> Self {
+        self.ordering = Some(Vec::new());
         self
     }
 
@@ -160,6 +320,143 @@ where
     }
 }
 
+/// [`Selector`] produced by [`QueryBuilder::with`]
+pub type WithRelated<S, F, P> = (S, <<F as Field>::Type as WithField<F, P>>::Selector);
+
+impl<E, S, C, LO> QueryBuilder<E, S, C, LO>
+where
+    S: Selector,
+{
+    /// Eagerly join a [`ForeignModel`](crate::fields::types::ForeignModel) and decode the related model
+    ///
+    /// This avoids a second query for the related model.
+    /// The query's result changes from `S::Result` to `(S::Result, RelatedModel)`:
+    ///
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # use rorm::fields::types::ForeignModel;
+    /// # #[derive(Model)] struct Customer { #[rorm(id)] id: i64 }
+    /// # #[derive(Model)] struct Order { #[rorm(id)] id: i64, customer: ForeignModel<Customer> }
+    /// # async fn func(db: &Database) {
+    /// let orders: Vec<(Order, Customer)> = query!(db, Order)
+    ///     .with(Order::F.customer)
+    ///     .all()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// An optional foreign model is [left joined](QueryBuilder::left_join) instead
+    /// and its related model is decoded as `Option<RelatedModel>`:
+    ///
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # use rorm::fields::types::ForeignModel;
+    /// # #[derive(Model)] struct Customer { #[rorm(id)] id: i64 }
+    /// # #[derive(Model)] struct Order { #[rorm(id)] id: i64, customer: Option<ForeignModel<Customer>> }
+    /// # async fn func(db: &Database) {
+    /// let orders: Vec<(Order, Option<Customer>)> = query!(db, Order)
+    ///     .with(Order::F.customer)
+    ///     .all()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// See [`WithField`] for the accepted field types.
+    pub fn with<F, P>(
+        mut self,
+        _field: FieldProxy<F, P>,
+    ) -> QueryBuilder<E, WithRelated<S, F, P>, C, LO>
+    where
+        F: Field,
+        F::Type: WithField<F, P>,
+        P: Path<Origin = S::Model>,
+        PathStep<F, P>: Path<Origin = S::Model>,
+    {
+        if <F::Type as WithField<F, P>>::LEFT_JOIN {
+            self.ctx.set_join_type::<PathStep<F, P>>(JoinType::LeftJoin);
+        }
+
+        #[rustfmt::skip]
+        let QueryBuilder { executor, ctx, selector, condition, lim_off, ordering, } = self;
+        let selector = (selector, Default::default());
+        #[rustfmt::skip]
+        return QueryBuilder { executor, ctx, selector, condition, lim_off, ordering, };
+    }
+}
+
+/// Type of a field which can be passed to [`QueryBuilder::with`]
+///
+/// - [`ForeignModelByField<_>`](ForeignModelByField) is joined and decoded as the related model
+/// - `Option<ForeignModelByField<_>>` is left joined and decoded as `Option` of the related model
+///
+/// [`BackRef`](crate::fields::types::BackRef)s are not supported,
+/// because they would return the queried model once per related model.
+pub trait WithField<F, P> {
+    sealed!(trait);
+
+    /// [`Selector`] decoding the related model
+    type Selector: Selector + Default;
+
+    /// Does the related model have to be left joined?
+    const LEFT_JOIN: bool;
+}
+impl<FF, F, P> WithField<F, P> for ForeignModelByField<FF>
+where
+    FF: SingleColumnField,
+    P: Path,
+    PathStep<F, P>: Path,
+{
+    sealed!(impl);
+
+    type Selector = PatchSelector<FF::Model, PathStep<F, P>>;
+
+    const LEFT_JOIN: bool = false;
+}
+impl<FF, F, P> WithField<F, P> for Option<ForeignModelByField<FF>>
+where
+    FF: SingleColumnField,
+    P: Path,
+    PathStep<F, P>: Path,
+    OptionalPatchSelector<FF::Model, PathStep<F, P>>: Selector,
+{
+    sealed!(impl);
+
+    type Selector = OptionalPatchSelector<FF::Model, PathStep<F, P>>;
+
+    const LEFT_JOIN: bool = true;
+}
+
+/// Get the ordering to query with
+///
+/// Falls back to the model's [default ordering](Model::DEFAULT_ORDERING)
+/// if no ordering has been set and the selector is [compatible](Selector::DEFAULT_ORDERING_COMPATIBLE).
+fn get_ordering<'a, S: Selector>(
+    ordering: Option<&'a [OrderByEntry<'static>]>,
+) -> &'a [OrderByEntry<'static>] {
+    match ordering {
+        Some(ordering) => ordering,
+        None if S::DEFAULT_ORDERING_COMPATIBLE => S::Model::DEFAULT_ORDERING,
+        None => &[],
+    }
+}
+
+/// Check that every alias a query is ordered by has been selected
+///
+/// This has to be called after the selector has been added to the context.
+fn check_ordering(ctx: &QueryContext, ordering: Option<&[OrderByEntry]>) -> Result<(), Error> {
+    for entry in ordering.unwrap_or_default() {
+        if entry.table_name.is_none() && !ctx.is_selected(entry.column_name) {
+            return Err(Error::ConfigurationError(format!(
+                "The query is ordered by `{}` which is not selected",
+                entry.column_name
+            )));
+        }
+    }
+    Ok(())
+}
+
 impl<'e, 'c, E, S, C, LO> QueryBuilder<E, S, C, LO>
 where
     E: Executor<'e>,
@@ -173,6 +470,7 @@ where
     {
         let decoder = self.selector.select(&mut self.ctx);
         self.condition.add_to_builder(&mut self.ctx);
+        check_ordering(&self.ctx, self.ordering.as_deref())?;
 
         let columns = self.ctx.get_selects();
         let joins = self.ctx.get_joins();
@@ -188,7 +486,7 @@ where
             &columns,
             &joins,
             condition.as_ref(),
-            self.ordering.as_slice(),
+            get_ordering::<S>(self.ordering.as_deref()),
             self.lim_off.into_option(),
         )
         .await?
@@ -211,6 +509,11 @@ where
     {
         let decoder = self.selector.select(&mut self.ctx);
         self.condition.add_to_builder(&mut self.ctx);
+        if let Err(error) = check_ordering(&self.ctx, self.ordering.as_deref()) {
+            return QueryStream::new(decoder, self.ctx, None, move |_, _| {
+                Box::pin(futures::stream::once(async move { Err(error) }))
+            });
+        }
 
         QueryStream::new(
             decoder,
@@ -224,7 +527,7 @@ where
                     ctx.get_selects().as_slice(),
                     ctx.get_joins().as_slice(),
                     condition.as_ref(),
-                    self.ordering.as_slice(),
+                    get_ordering::<S>(self.ordering.as_deref()),
                     self.lim_off.into_option(),
                 )
             },
@@ -240,6 +543,7 @@ where
     {
         let decoder = self.selector.select(&mut self.ctx);
         self.condition.add_to_builder(&mut self.ctx);
+        check_ordering(&self.ctx, self.ordering.as_deref())?;
 
         let columns = self.ctx.get_selects();
         let joins = self.ctx.get_joins();
@@ -255,7 +559,7 @@ where
             &columns,
             &joins,
             condition.as_ref(),
-            self.ordering.as_slice(),
+            get_ordering::<S>(self.ordering.as_deref()),
             self.lim_off.into_option(),
         )
         .await?;
@@ -271,6 +575,7 @@ where
     {
         let decoder = self.selector.select(&mut self.ctx);
         self.condition.add_to_builder(&mut self.ctx);
+        check_ordering(&self.ctx, self.ordering.as_deref())?;
 
         let columns = self.ctx.get_selects();
         let joins = self.ctx.get_joins();
@@ -286,7 +591,7 @@ where
             &columns,
             &joins,
             condition.as_ref(),
-            self.ordering.as_slice(),
+            get_ordering::<S>(self.ordering.as_deref()),
             self.lim_off.into_option(),
         )
         .await?;
@@ -301,6 +606,119 @@ where
     }
 }
 
+impl<'e, 'c, E, S, C> QueryBuilder<E, S, C, ()>
+where
+    E: Executor<'e>,
+    S: Selector,
+    C: ConditionMarker<'c>,
+{
+    /// Compute an aggregation over all matching rows
+    ///
+    /// This replaces the query's selector and ignores any ordering.
+    /// ```no_run
+    /// # use rorm::{Model, Database, query, FieldAccess};
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64, age: i16 }
+    /// # async fn func(db: &Database) {
+    /// let average_age: Option<f64> = query!(db, User)
+    ///     .condition(User::F.age.greater_equals(18))
+    ///     .aggregate(User::F.age.avg())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn aggregate<A, F, P>(
+        self,
+        aggregation: AggregatedColumn<A, F, P>,
+    ) -> Result<<AggregatedColumn<A, F, P> as Selector>::Result, Error>
+    where
+        A: AggregationFunc,
+        F: SingleColumnField,
+        F::Type: AsDbType,
+        P: Path<Origin = S::Model>,
+    {
+        #[rustfmt::skip]
+        let QueryBuilder { executor, ctx, condition, lim_off, .. } = self;
+        #[rustfmt::skip]
+        return QueryBuilder { executor, ctx, selector: aggregation, condition, lim_off, ordering: Some(Vec::new()), }.one().await;
+    }
+
+    /// Count the matching rows
+    ///
+    /// This is a shorthand for [`aggregate`](QueryBuilder::aggregate)
+    /// using `COUNT` over the model's primary key.
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64 }
+    /// # async fn func(db: &Database) {
+    /// let users = query!(db, User).count().await.unwrap();
+    /// # }
+    /// ```
+    pub async fn count(self) -> Result<u64, Error>
+    where
+        <<S::Model as Model>::Primary as Field>::Type: AsDbType,
+    {
+        let primary = FieldProxy::<<S::Model as Model>::Primary, S::Model>::new();
+        let count = self.aggregate(primary.count()).await?;
+        Ok(count as u64)
+    }
+}
+
+impl<'c, S, C> QueryBuilder<&Database, S, C, Limit<u64>>
+where
+    S: Selector,
+    C: ConditionMarker<'c> + Clone,
+{
+    /// Retrieve and decode all matching rows of the page
+    /// together with the total number of matching rows
+    ///
+    /// Both queries are executed in a single transaction.
+    /// The count uses the same condition and joins (including their types
+    /// set by [`left_join`](QueryBuilder::left_join) or [`with`](QueryBuilder::with))
+    /// as the page, so `total` counts the rows the pages are taken from.
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # use rorm::db::sql::ordering::Ordering;
+    /// # use rorm::fields::types::ForeignModel;
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String }
+    /// # #[derive(Model)] struct Post { #[rorm(id)] id: i64, author: Option<ForeignModel<User>> }
+    /// # async fn func(db: &Database) {
+    /// // Posts without an author are included both in the page and in the total
+    /// let (posts, total) = query!(db, Post)
+    ///     .left_join(Post::F.author)
+    ///     .order_by(Post::F.author.name, Ordering::Asc)
+    ///     .paginate(2, 20)
+    ///     .all_with_count()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn all_with_count(self) -> Result<(Vec<S::Result>, u64), Error>
+    where
+        <<S::Model as Model>::Primary as Field>::Type: AsDbType,
+    {
+        #[rustfmt::skip]
+        let QueryBuilder { executor, ctx, selector, condition, lim_off, ordering, } = self;
+        let mut tx = executor.start_transaction().await?;
+
+        let total = QueryBuilder {
+            executor: &mut tx,
+            ctx: ctx.clone(),
+            selector: FieldProxy::<<S::Model as Model>::Primary, S::Model>::new(),
+            condition: condition.clone(),
+            lim_off: (),
+            ordering: None,
+        }
+        .count()
+        .await?;
+
+        #[rustfmt::skip]
+        let page = QueryBuilder { executor: &mut tx, ctx, selector, condition, lim_off, ordering, }.all().await?;
+
+        tx.commit().await?;
+        Ok((page, total))
+    }
+}
+
 /// Create a SELECT query.
 ///
 /// 1. Give a reference to your db and the patch to query.
@@ -311,6 +729,10 @@ where
 ///
 ///     `query!(&db, (MyModelType::F.some_field, MyModelType::F.another_field, ))`
 ///
+///     Such a tuple may contain up to 32 fields (or aggregations) which may be accessed through relations:
+///
+///     `query!(&db, (MyModelType::F.some_field, MyModelType::F.some_relation.other_field, ))`
+///
 /// 2. Set a condition which rows to query.
 ///
 ///     `.condition(MyModelType::F.some_field.equals("some_value"))`
@@ -340,6 +762,10 @@ where
 ///
 ///         `.optional().await`
 ///
+///     - [`count`](QueryBuilder::count) the matching rows or compute an [`aggregate`](QueryBuilder::aggregate).
+///
+///         `.count().await`
+///
 ///     Each of these methods decodes the database's rows into the patch you specified in step 1.
 ///     If you want to work with raw rows, each of the methods in step 4 has a `*_as_row` twin.
 ///
@@ -583,6 +1009,35 @@ pub struct Limit<O: OffsetMarker> {
     /// Optional offset to begin query at
     offset: O,
 }
+impl Limit<u64> {
+    /// The limit and offset selecting a single page
+    ///
+    /// Pages are counted starting with `0`.
+    /// The offset saturates at `u64::MAX` instead of overflowing,
+    /// so a page lying (partially) beyond it is shortened accordingly:
+    /// ```
+    /// use rorm::crud::query::{Limit, LimitMarker};
+    ///
+    /// let clause = Limit::page(2, 20).into_option().unwrap();
+    /// assert_eq!((clause.limit, clause.offset), (20, Some(40)));
+    ///
+    /// // The last page's end would be u64::MAX + 1
+    /// let clause = Limit::page(u64::MAX / 2, 2).into_option().unwrap();
+    /// assert_eq!((clause.limit, clause.offset), (1, Some(u64::MAX - 1)));
+    ///
+    /// // The page's start would be past u64::MAX
+    /// let clause = Limit::page(u64::MAX, 20).into_option().unwrap();
+    /// assert_eq!((clause.limit, clause.offset), (0, Some(u64::MAX)));
+    /// ```
+    pub fn page(page: u64, per_page: u64) -> Self {
+        let offset = page.saturating_mul(per_page);
+        let end = offset.saturating_add(per_page);
+        Limit {
+            limit: end - offset,
+            offset,
+        }
+    }
+}
 impl<O: OffsetMarker> LimitMarker for Limit<O> {
     sealed!(impl);
 
@@ -10,7 +10,7 @@ use crate::internal::field::decoder::FieldDecoder;
 use crate::internal::field::{Field, FieldProxy, SingleColumnField};
 use crate::internal::query_context::QueryContext;
 use crate::internal::relation_path::{Path, PathImpl, PathStep, ResolvedRelatedField};
-use crate::model::{Model, PatchSelector};
+use crate::model::{Model, OptionalPatchSelector, PatchSelector};
 use crate::Patch;
 
 /// Something which "selects" a value from a certain table,
@@ -28,6 +28,12 @@ pub trait Selector {
     /// Can this selector be used in insert queries to specify the returning expression?
     const INSERT_COMPATIBLE: bool;
 
+    /// Can the query be ordered by its model's [default ordering](Model::DEFAULT_ORDERING)?
+    ///
+    /// This is `false` for aggregations,
+    /// because a query using them can't be ordered by columns which are neither aggregated nor grouped.
+    const DEFAULT_ORDERING_COMPATIBLE: bool;
+
     /// Constructs a decoder and configures a [`QueryContext`] to query the required columns
     fn select(self, ctx: &mut QueryContext) -> Self::Decoder;
 }
@@ -41,6 +47,7 @@ where
     type Model = P::Origin;
     type Decoder = <F::Type as FieldType>::Decoder;
     const INSERT_COMPATIBLE: bool = P::IS_ORIGIN;
+    const DEFAULT_ORDERING_COMPATIBLE: bool = true;
 
     fn select(self, ctx: &mut QueryContext) -> Self::Decoder {
         FieldDecoder::new(ctx, FieldProxy::<F, P>::new())
@@ -54,14 +61,37 @@ where
     P: Path,
     PathStep<F, P>: PathImpl<F::Type>,
 {
-    pub fn select_as<Ptch>(self) -> PatchSelector<Ptch, PathStep<F, P>>
+    pub fn select_as<Ptch>(self) -> Ptch::Selector
     where
-        Ptch: Patch<Model = <ResolvedRelatedField<F, P> as Field>::Model>,
+        Ptch: SelectAs<PathStep<F, P>, Model = <ResolvedRelatedField<F, P> as Field>::Model>,
     {
-        PatchSelector::new()
+        Default::default()
     }
 }
 
+/// A [`Patch`] or an `Option<impl Patch>` which can be selected through a relation
+///
+/// i.e. `query!(db, (Model::F.relation as Patch,))` or `query!(db, (Model::F.relation as Option<Patch>,))`
+#[doc(hidden)]
+pub trait SelectAs<Pth: Path> {
+    /// The patch's model
+    type Model: Model;
+
+    /// The [`Selector`] to use
+    type Selector: Selector + Default;
+}
+impl<Ptch: Patch, Pth: Path> SelectAs<Pth> for Ptch {
+    type Model = Ptch::Model;
+    type Selector = PatchSelector<Ptch, Pth>;
+}
+impl<Ptch: Patch, Pth: Path> SelectAs<Pth> for Option<Ptch>
+where
+    OptionalPatchSelector<Ptch, Pth>: Selector,
+{
+    type Model = Ptch::Model;
+    type Selector = OptionalPatchSelector<Ptch, Pth>;
+}
+
 impl<A, F, P> Selector for AggregatedColumn<A, F, P>
 where
     A: AggregationFunc,
@@ -73,6 +103,7 @@ where
     type Model = P::Origin;
     type Decoder = DirectDecoder<Self::Result>;
     const INSERT_COMPATIBLE: bool = false;
+    const DEFAULT_ORDERING_COMPATIBLE: bool = false;
 
     fn select(self, ctx: &mut QueryContext) -> Self::Decoder {
         let (index, column) = ctx.select_aggregation::<A, F, P>();
@@ -100,6 +131,8 @@ macro_rules! selectable {
 
             const INSERT_COMPATIBLE: bool = $($S::INSERT_COMPATIBLE &&)+ true;
 
+            const DEFAULT_ORDERING_COMPATIBLE: bool = $($S::DEFAULT_ORDERING_COMPATIBLE &&)+ true;
+
             fn select(self, ctx: &mut QueryContext) -> Self::Decoder {
                 ($(
                     self.$index.select(ctx),
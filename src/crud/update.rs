@@ -121,6 +121,16 @@ impl<'rf, E, M, C> UpdateBuilder<'rf, E, M, OptionalColumns<'rf>, C> {
         }
     }
 
+    /// Set a nullable column to `NULL`.
+    ///
+    /// Can be called multiple times.
+    pub fn set_null<F, T>(self, field: FieldProxy<F, M>) -> Self
+    where
+        F: SingleColumnField<Type = Option<T>>,
+    {
+        self.set(field, None)
+    }
+
     /// Go back to a "normal" builder after calling [`begin_dyn_set`](UpdateBuilder::begin_dyn_set).
     ///
     /// This will check if `set` has been called at least once.
@@ -159,6 +169,19 @@ where
         #[rustfmt::skip]
         return UpdateBuilder { executor, columns: vec![(F::NAME, F::type_into_value(value))], _phantom, condition, };
     }
+
+    /// Set a nullable column to `NULL`.
+    ///
+    /// Can be called multiple times.
+    pub fn set_null<F, T>(
+        self,
+        field: FieldProxy<F, M>,
+    ) -> UpdateBuilder<'rf, E, M, Vec<(&'static str, Value<'rf>)>, C>
+    where
+        F: SingleColumnField<Type = Option<T>>,
+    {
+        self.set(field, None)
+    }
 }
 
 impl<'rf, E, M, C> UpdateBuilder<'rf, E, M, Vec<(&'static str, Value<'rf>)>, C>
@@ -173,6 +196,16 @@ where
         builder.columns.push((F::NAME, F::type_into_value(value)));
         builder
     }
+
+    /// Set a nullable column to `NULL`.
+    ///
+    /// Can be called multiple times.
+    pub fn set_null<F, T>(self, field: FieldProxy<F, M>) -> Self
+    where
+        F: SingleColumnField<Type = Option<T>>,
+    {
+        self.set(field, None)
+    }
 }
 
 impl<'ex, 'rf, E, M, C> UpdateBuilder<'rf, E, M, Vec<(&'static str, Value<'rf>)>, C>
@@ -182,7 +215,65 @@ where
     C: ConditionMarker<'rf>,
 {
     /// Perform the update operation
-    pub async fn exec(self) -> Result<u64, Error> {
+    ///
+    /// Fields annotated with `#[rorm(auto_update_time)]` which haven't been set explicitly,
+    /// are set to the current time.
+    /// Their columns are listed in [`Model::AUTO_UPDATE_TIME_COLUMNS`]
+    /// and their values are computed using [`Model::default_fn_value`]:
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use rorm::conditions::Value;
+    /// use rorm::{update, Database, Error, FieldAccess, Model};
+    ///
+    /// #[derive(Model)]
+    /// struct Post {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     #[rorm(max_length = 255)]
+    ///     title: String,
+    ///
+    ///     #[rorm(auto_update_time)]
+    ///     updated_at: DateTime<Utc>,
+    /// }
+    ///
+    /// async fn rename(db: &Database, id: i64, title: String) -> Result<u64, Error> {
+    ///     // Sets `title` and `updated_at`
+    ///     update!(db, Post)
+    ///         .set(Post::F.title, title)
+    ///         .condition(Post::F.id.equals(id))
+    ///         .exec()
+    ///         .await
+    /// }
+    ///
+    /// async fn backdate(db: &Database, id: i64, updated_at: DateTime<Utc>) -> Result<u64, Error> {
+    ///     // Only sets `updated_at` to the explicitly given value
+    ///     update!(db, Post)
+    ///         .set(Post::F.updated_at, updated_at)
+    ///         .condition(Post::F.id.equals(id))
+    ///         .exec()
+    ///         .await
+    /// }
+    ///
+    /// assert_eq!(Post::AUTO_UPDATE_TIME_COLUMNS, ["updated_at"]);
+    ///
+    /// let before = Utc::now();
+    /// let Some(Value::ChronoDateTime(updated_at)) = Post::default_fn_value("updated_at") else {
+    ///     panic!("updated_at should be set to the current time");
+    /// };
+    /// assert!(before <= updated_at && updated_at <= Utc::now());
+    /// ```
+    pub async fn exec(mut self) -> Result<u64, Error> {
+        for column in M::AUTO_UPDATE_TIME_COLUMNS {
+            if !self.columns.iter().any(|(name, _)| name == column) {
+                self.columns.push((
+                    column,
+                    M::default_fn_value(column)
+                        .expect("AUTO_UPDATE_TIME_COLUMNS should only contain columns which have a default_fn_value"),
+                ));
+            }
+        }
+
         let context = QueryContext::new();
         let columns: Vec<_> = self
             .columns
@@ -234,6 +325,20 @@ where
 /// `update!`'s first argument is a reference to the [`Database`](crate::Database).
 /// Its second is the [`Model`] type you want to update rows of.
 ///
+/// # Setting `NULL`
+/// Nullable fields can be cleared using [`set_null`](UpdateBuilder::set_null) instead of `set(field, None)`:
+/// ```no_run
+/// # use rorm::{Model, Database, update, FieldAccess};
+/// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] nickname: Option<String>, }
+/// pub async fn clear_nickname(db: &Database, id: i64) {
+///     update!(db, User)
+///         .set_null(User::F.nickname)
+///         .condition(User::F.id.equals(id))
+///         .await
+///         .unwrap();
+/// }
+/// ```
+///
 /// # Dynamic number of [`set`](UpdateBuilder::set)
 /// ```no_run
 /// # use std::collections::HashMap;
@@ -9,6 +9,7 @@
 //! - [`f64`]
 //! - [`String`]
 //! - [`Vec<u8>`]
+//! - [`Duration`](std::time::Duration) (stored as microseconds, see [below](#duration))
 //! - [`Option<T>`] where `T` is on this list
 //!
 //! # Our types
@@ -36,6 +37,42 @@
 //! # url types (requires the "url" feature)
 //! - [`Url`](url::Url)
 //!
+//! # Duration
+//! A [`Duration`](std::time::Duration) is stored as a 64-bit integer counting microseconds.
+//! - Any precision below a microsecond is truncated.
+//! - Durations longer than `i64::MAX` microseconds (~292,000 years) are saturated to `i64::MAX`.
+//!
+//! ```
+//! use std::time::Duration;
+//! use rorm::{FieldAccess, Model};
+//! use rorm::conditions::Value;
+//! use rorm::db::sql::value::NullType;
+//! use rorm::fields::traits::FieldType;
+//!
+//! #[derive(Model)]
+//! struct Job {
+//!     #[rorm(id)]
+//!     id: i64,
+//!
+//!     timeout: Option<Duration>,
+//! }
+//!
+//! let [value] = Duration::from_nanos(1_999).into_values();
+//! assert!(matches!(value, Value::I64(1)));
+//!
+//! let [value] = Duration::MAX.into_values();
+//! assert!(matches!(value, Value::I64(i64::MAX)));
+//!
+//! let [value] = None::<Duration>.into_values();
+//! assert!(matches!(value, Value::Null(NullType::I64)));
+//!
+//! let condition = Job::F.timeout.equals(Some(Duration::from_millis(1_500)));
+//! assert!(matches!(condition.snd_arg, Value::I64(1_500_000)));
+//! ```
+//!
+//! # Custom types
+//! Other types stored in a single column can be added using [`impl_field_type!`](crate::impl_field_type).
+//!
 //! ---
 //!
 //! ```no_run
@@ -97,7 +97,11 @@ pub trait FieldRegexp<'rhs, Rhs: 'rhs, Any = ()>: FieldType {
     fn field_not_regexp<A: FieldAccess>(access: A, value: Rhs) -> Self::NrCond<A>;
 }
 
-// TODO: null check, BETWEEN, IN
+/// Marker for field types which can be `NULL`, i.e. `Option<T>`.
+///
+/// It enables [`FieldAccess::is_null`] and [`FieldAccess::is_not_null`].
+pub trait FieldNullable: FieldType {}
+impl<T> FieldNullable for Option<T> where Option<T>: FieldType {}
 
 /// Provides the "default" implementation of [`FieldEq`].
 ///
@@ -260,3 +264,38 @@ where
         }
     }
 }
+
+/// Provides the "default" implementation of [`FieldLike`].
+///
+/// It takes
+/// - the left hand side type i.e. type to implement on
+/// - the right hand side (use `'rhs` a lifetime if required)
+/// - a closure to convert the right hand side into a [`Value`]
+#[doc(hidden)]
+#[allow(non_snake_case)] // makes it clearer that a trait and which trait is meant
+#[macro_export]
+macro_rules! impl_FieldLike {
+    ($lhs:ty, $rhs:ty, $into_value:expr) => {
+        impl<'rhs> $crate::fields::traits::cmp::FieldLike<'rhs, $rhs> for $lhs {
+            type LiCond<A: $crate::FieldAccess> = $crate::conditions::Binary<$crate::conditions::Column<A>, $crate::conditions::Value<'rhs>>;
+            fn field_like<A: $crate::FieldAccess>(access: A, value: $rhs) -> Self::LiCond<A> {
+                $crate::conditions::Binary {
+                    operator: $crate::conditions::BinaryOperator::Like,
+                    fst_arg: $crate::conditions::Column(access),
+                    #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                    snd_arg: $into_value(value),
+                }
+            }
+
+            type NlCond<A: $crate::FieldAccess> = $crate::conditions::Binary<$crate::conditions::Column<A>, $crate::conditions::Value<'rhs>>;
+            fn field_not_like<A: $crate::FieldAccess>(access: A, value: $rhs) -> Self::NlCond<A> {
+                $crate::conditions::Binary {
+                    operator: $crate::conditions::BinaryOperator::NotLike,
+                    fst_arg: $crate::conditions::Column(access),
+                    #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                    snd_arg: $into_value(value),
+                }
+            }
+        }
+    };
+}
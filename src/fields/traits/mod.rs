@@ -43,3 +43,176 @@ pub trait FieldType: 'static {
     /// `const fn<F: Field>() -> Self::Columns<&'static str>`
     type ColumnsFromName<F: Field<Type = Self>>: ColumnsFromName<F>;
 }
+
+/// Field types which can be set to the current time by `#[rorm(auto_create_time)]` and `#[rorm(auto_update_time)]`
+///
+/// When inserting a patch which doesn't contain such a field, it is set to [`AutoTime::now`].
+/// `#[rorm(auto_update_time)]` fields are also set on every [`update!`](crate::update),
+/// unless they are set explicitly.
+///
+/// It is implemented for the chrono and time types (if their features are enabled)
+/// and for `Option<T>` where `T: AutoTime`.
+/// ```
+/// use chrono::{DateTime, NaiveDate, Utc};
+/// use rorm::conditions::Value;
+/// use rorm::fields::traits::AutoTime;
+/// use rorm::Model;
+///
+/// #[derive(Model)]
+/// struct Post {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     #[rorm(auto_create_time)]
+///     created_at: DateTime<Utc>,
+///
+///     #[rorm(auto_update_time)]
+///     updated_at: Option<DateTime<Utc>>,
+/// }
+///
+/// assert_eq!(Post::DEFAULT_FN_COLUMNS, ["created_at", "updated_at"]);
+/// assert_eq!(Post::AUTO_UPDATE_TIME_COLUMNS, ["updated_at"]);
+///
+/// let before = Utc::now();
+/// let Some(Value::ChronoDateTime(created_at)) = Post::default_fn_value("created_at") else {
+///     panic!("created_at should be set to the current time");
+/// };
+/// assert!(before <= created_at && created_at <= Utc::now());
+///
+/// assert!(<Option<NaiveDate>>::now().is_some());
+/// ```
+pub trait AutoTime: FieldType {
+    /// Get the current time
+    fn now() -> Self;
+}
+impl<T: AutoTime> AutoTime for Option<T>
+where
+    Option<T>: FieldType,
+{
+    fn now() -> Self {
+        Some(T::now())
+    }
+}
+
+/// Implement [`FieldType`] for a custom type which is stored in a single column
+///
+/// The custom type is converted to and from a `base` type,
+/// which has to be one of the std or chrono / time types rorm supports out of the box.
+/// Its database type and implicit annotations (for example `String` requiring `max_length`) are reused.
+///
+/// Both `Custom` and `Option<Custom>` can then be used as fields
+/// and `Custom` can be compared with itself using [`FieldEq`].
+///
+/// ## Usage
+/// ```no_run
+/// use rorm::{impl_field_type, Error, FieldAccess, Model};
+///
+/// #[derive(Copy, Clone, Debug)]
+/// pub struct Money(i64);
+///
+/// impl_field_type!(
+///     Money,
+///     i64,
+///     into: |money: &Money| money.0,
+///     from: |cents: i64| Ok(Money(cents)),
+/// );
+///
+/// #[derive(Clone, Debug)]
+/// pub enum Tier {
+///     Free,
+///     Paid,
+/// }
+///
+/// impl_field_type!(
+///     Tier,
+///     String,
+///     into: |tier: &Tier| match tier {
+///         Tier::Free => "free".to_string(),
+///         Tier::Paid => "paid".to_string(),
+///     },
+///     from: |tier: String| match tier.as_str() {
+///         "free" => Ok(Tier::Free),
+///         "paid" => Ok(Tier::Paid),
+///         _ => Err(Error::DecodeError(format!("Unknown tier: {tier}"))),
+///     },
+/// );
+///
+/// #[derive(Model)]
+/// pub struct Account {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     balance: Money,
+///
+///     credit: Option<Money>,
+///
+///     #[rorm(max_length = 16)]
+///     tier: Tier,
+/// }
+///
+/// let condition = Account::F.balance.equals(Money(0));
+/// ```
+///
+/// - `into` is used to convert `&Custom` into `base` (must implement `Fn(&Custom) -> Base`)
+/// - `from` is used to convert `base` back into `Custom` (must implement `Fn(Base) -> Result<Custom, rorm::Error>`)
+#[macro_export]
+macro_rules! impl_field_type {
+    ($type:ty, $base:ty, into: $into:expr, from: $from:expr $(,)?) => {
+        impl $crate::internal::field::custom_type::CustomFieldType for $type {
+            type Base = $base;
+
+            fn into_value(self) -> $crate::conditions::Value<'static> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                let [value] = <$base as $crate::fields::traits::FieldType>::into_values($into(&self));
+                value
+            }
+
+            fn as_value(&self) -> $crate::conditions::Value<'_> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                let [value] = <$base as $crate::fields::traits::FieldType>::into_values($into(self));
+                value
+            }
+
+            fn from_base(base: $base) -> Result<Self, $crate::Error> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                $from(base)
+            }
+        }
+
+        impl $crate::fields::traits::FieldType for $type {
+            type Columns<T> = [T; 1];
+
+            fn into_values(self) -> Self::Columns<$crate::conditions::Value<'static>> {
+                [$crate::internal::field::custom_type::CustomFieldType::into_value(self)]
+            }
+
+            fn as_values(&self) -> Self::Columns<$crate::conditions::Value<'_>> {
+                [$crate::internal::field::custom_type::CustomFieldType::as_value(self)]
+            }
+
+            fn get_imr<F: $crate::internal::field::Field<Type = Self>>() -> Self::Columns<$crate::internal::imr::Field> {
+                $crate::internal::field::as_db_type::get_single_imr::<F>(
+                    <<$base as $crate::internal::field::as_db_type::AsDbType>::DbType as $crate::internal::hmr::db_type::DbType>::IMR
+                )
+            }
+
+            type Decoder = $crate::internal::field::custom_type::CustomDecoder<Self>;
+
+            type AnnotationsModifier<F: $crate::internal::field::Field<Type = Self>> = $crate::internal::field::modifier::MergeAnnotations<Self>;
+
+            type CheckModifier<F: $crate::internal::field::Field<Type = Self>> = $crate::internal::field::modifier::SingleColumnCheck<<$base as $crate::internal::field::as_db_type::AsDbType>::DbType>;
+
+            type ColumnsFromName<F: $crate::internal::field::Field<Type = Self>> = $crate::internal::field::modifier::SingleColumnFromName;
+        }
+        impl $crate::internal::field::as_db_type::AsDbType for $type {
+            type Primitive = <$base as $crate::internal::field::as_db_type::AsDbType>::Primitive;
+            type DbType = <$base as $crate::internal::field::as_db_type::AsDbType>::DbType;
+
+            const IMPLICIT: Option<$crate::internal::hmr::annotations::Annotations> = <$base as $crate::internal::field::as_db_type::AsDbType>::IMPLICIT;
+        }
+
+        $crate::impl_FieldEq!(impl<'rhs> FieldEq<'rhs, $type> for $type {
+            $crate::internal::field::custom_type::CustomFieldType::into_value
+        });
+    };
+}
@@ -2,6 +2,7 @@ use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rorm_db::sql::value::NullType;
 
 use crate::conditions::Value;
+use crate::fields::traits::AutoTime;
 use crate::internal::hmr::db_type;
 use crate::{impl_AsDbType, impl_FieldEq, impl_FieldOrd};
 
@@ -44,3 +45,24 @@ impl_FieldOrd!(
         .map(Value::ChronoDateTime)
         .unwrap_or(Value::Null(NullType::ChronoDateTime))
 );
+
+impl AutoTime for NaiveTime {
+    fn now() -> Self {
+        Utc::now().time()
+    }
+}
+impl AutoTime for NaiveDate {
+    fn now() -> Self {
+        Utc::now().date_naive()
+    }
+}
+impl AutoTime for NaiveDateTime {
+    fn now() -> Self {
+        Utc::now().naive_utc()
+    }
+}
+impl AutoTime for DateTime<Utc> {
+    fn now() -> Self {
+        Utc::now()
+    }
+}
@@ -7,14 +7,15 @@ use rorm_declaration::imr;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use crate::conditions::Value;
-use crate::fields::traits::FieldType;
+use crate::conditions::{self, BinaryOperator, Column, Value};
+use crate::fields::traits::{FieldEq, FieldType};
 use crate::internal::field::as_db_type::{get_single_imr, AsDbType};
 use crate::internal::field::modifier::{MergeAnnotations, SingleColumnCheck, SingleColumnFromName};
 use crate::internal::field::Field;
 use crate::internal::hmr::db_type::{Binary, DbType};
 use crate::new_converting_decoder;
 use crate::Error::DecodeError;
+use crate::FieldAccess;
 
 /// Stores data by serializing it to json.
 ///
@@ -33,6 +34,46 @@ use crate::Error::DecodeError;
 ///     pub data: Json<HashMap<String, String>>,
 /// }
 /// ```
+///
+/// A json field can be compared with a [`serde_json::Value`].
+/// This compares the serialized bytes,
+/// so it only matches if `T` serializes its keys in the same order as the `serde_json::Value`.
+/// A `serde_json::Value` sorts its keys (unless serde_json's "preserve_order" feature is enabled),
+/// while a struct serializes its fields in declaration order:
+///
+/// ```
+/// use rorm::{FieldAccess, Model};
+/// use rorm::conditions::Value;
+/// use rorm::fields::types::Json;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Serialize, Deserialize)]
+/// pub struct Point {
+///     pub y: i32,
+///     pub x: i32,
+/// }
+///
+/// #[derive(Model)]
+/// pub struct Marker {
+///     #[rorm(id)]
+///     pub id: i64,
+///
+///     pub position: Json<Point>,
+/// }
+///
+/// // The bytes stored for `Json(Point { y: 2, x: 1 })`
+/// let stored = serde_json::to_vec(&Point { y: 2, x: 1 }).unwrap();
+/// assert_eq!(stored, br#"{"y":2,"x":1}"#);
+///
+/// // The bytes compared against by a condition using the same point as `serde_json::Value`
+/// let condition = Marker::F.position.equals(json!({"y": 2, "x": 1}));
+/// let Value::Binary(compared) = condition.snd_arg else { unreachable!() };
+/// assert_eq!(compared.as_ref(), br#"{"x":1,"y":2}"#);
+///
+/// // So the condition won't match the row
+/// assert_ne!(stored, compared.as_ref());
+/// ```
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Json<T: Serialize + DeserializeOwned>(pub T);
 
@@ -126,6 +167,41 @@ impl<T: Serialize + DeserializeOwned + 'static> AsDbType for Option<Json<T>> {
     type DbType = Binary;
 }
 
+/// Implements [`FieldEq`] for a json field type comparing it with a [`serde_json::Value`]
+///
+/// See [`Json`]'s docs for the comparison's caveat.
+macro_rules! impl_json_value_eq {
+    ($lhs:ty, $rhs:ty) => {
+        impl<'rhs, T: Serialize + DeserializeOwned + 'static> FieldEq<'rhs, $rhs> for $lhs {
+            type EqCond<A: FieldAccess> = conditions::Binary<Column<A>, Value<'rhs>>;
+            fn field_equals<A: FieldAccess>(access: A, value: $rhs) -> Self::EqCond<A> {
+                conditions::Binary {
+                    operator: BinaryOperator::Equals,
+                    fst_arg: Column(access),
+                    snd_arg: json_value_into_value(&value),
+                }
+            }
+
+            type NeCond<A: FieldAccess> = conditions::Binary<Column<A>, Value<'rhs>>;
+            fn field_not_equals<A: FieldAccess>(access: A, value: $rhs) -> Self::NeCond<A> {
+                conditions::Binary {
+                    operator: BinaryOperator::NotEquals,
+                    fst_arg: Column(access),
+                    snd_arg: json_value_into_value(&value),
+                }
+            }
+        }
+    };
+}
+impl_json_value_eq!(Json<T>, serde_json::Value);
+impl_json_value_eq!(Json<T>, &'rhs serde_json::Value);
+impl_json_value_eq!(Option<Json<T>>, serde_json::Value);
+impl_json_value_eq!(Option<Json<T>>, &'rhs serde_json::Value);
+fn json_value_into_value(value: &serde_json::Value) -> Value<'static> {
+    // Serializing a `serde_json::Value` can't fail
+    Value::Binary(Cow::Owned(serde_json::to_vec(value).unwrap()))
+}
+
 // From
 impl<T: Serialize + DeserializeOwned> From<T> for Json<T> {
     fn from(value: T) -> Self {
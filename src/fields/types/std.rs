@@ -1,9 +1,11 @@
 use std::borrow::Cow;
+use std::time::Duration;
 
 use crate::conditions::Value;
 use crate::db::sql::value::NullType;
+use crate::internal::field::custom_type::CustomFieldType;
 use crate::internal::hmr::db_type;
-use crate::{impl_AsDbType, impl_FieldEq, impl_FieldOrd};
+use crate::{impl_AsDbType, impl_FieldEq, impl_FieldLike, impl_FieldOrd, impl_field_type, Error};
 
 impl_AsDbType!(bool, db_type::Boolean, Value::Bool);
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, bool> for bool { Value::Bool });
@@ -62,6 +64,10 @@ impl_FieldOrd!(String, &'rhs str, conv_string);
 impl_FieldOrd!(String, &'rhs String, conv_string);
 impl_FieldOrd!(String, String, conv_string);
 impl_FieldOrd!(String, Cow<'rhs, str>, conv_string);
+impl_FieldLike!(String, &'rhs str, conv_string);
+impl_FieldLike!(String, &'rhs String, conv_string);
+impl_FieldLike!(String, String, conv_string);
+impl_FieldLike!(String, Cow<'rhs, str>, conv_string);
 fn conv_string<'a>(value: impl Into<Cow<'a, str>>) -> Value<'a> {
     Value::String(value.into())
 }
@@ -82,3 +88,18 @@ impl_FieldOrd!(Vec<u8>, Cow<'rhs, [u8]>, conv_bytes);
 fn conv_bytes<'a>(value: impl Into<Cow<'a, [u8]>>) -> Value<'a> {
     Value::Binary(value.into())
 }
+
+// Stored as microseconds (truncating), saturated at `i64::MAX` (see `rorm::fields`)
+impl_field_type!(
+    Duration,
+    i64,
+    into: |duration: &Duration| i64::try_from(duration.as_micros()).unwrap_or(i64::MAX),
+    from: |micros: i64| u64::try_from(micros)
+        .map(Duration::from_micros)
+        .map_err(|_| Error::DecodeError(format!("Invalid value '{micros}' for a duration"))),
+);
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Option<Duration>> for Option<Duration> { |option: Self| option.map(CustomFieldType::into_value).unwrap_or(Value::Null(NullType::I64)) });
+impl_FieldOrd!(Duration, Duration, CustomFieldType::into_value);
+impl_FieldOrd!(Option<Duration>, Option<Duration>, |option: Self| option
+    .map(CustomFieldType::into_value)
+    .unwrap_or(Value::Null(NullType::I64)));
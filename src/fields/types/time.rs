@@ -2,6 +2,7 @@ use rorm_db::sql::value::NullType;
 use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
 
 use crate::conditions::Value;
+use crate::fields::traits::AutoTime;
 use crate::internal::hmr::db_type;
 use crate::{impl_AsDbType, impl_FieldEq, impl_FieldOrd};
 
@@ -52,3 +53,25 @@ impl_FieldOrd!(
         .map(Value::TimePrimitiveDateTime)
         .unwrap_or(Value::Null(NullType::TimePrimitiveDateTime))
 );
+
+impl AutoTime for Time {
+    fn now() -> Self {
+        OffsetDateTime::now_utc().time()
+    }
+}
+impl AutoTime for Date {
+    fn now() -> Self {
+        OffsetDateTime::now_utc().date()
+    }
+}
+impl AutoTime for PrimitiveDateTime {
+    fn now() -> Self {
+        let now = OffsetDateTime::now_utc();
+        PrimitiveDateTime::new(now.date(), now.time())
+    }
+}
+impl AutoTime for OffsetDateTime {
+    fn now() -> Self {
+        OffsetDateTime::now_utc()
+    }
+}
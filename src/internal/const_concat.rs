@@ -131,6 +131,25 @@ impl ConstString<2048> {
     }
 }
 
+impl ConstString<2048> {
+    /// Concatenate strings into a select alias
+    ///
+    /// Unlike [`ConstString::join_alias`], this panics if the alias would be longer than 2048 bytes.
+    /// Used in a constant, this makes the compilation fail instead of producing a wrong alias.
+    pub(crate) const fn select_alias(strings: &[&str]) -> Self {
+        let mut string = Self::new();
+        sugar! {
+            for slice in strings {
+                match string.push_str(slice) {
+                    Some(ok) => {string = ok;},
+                    None => panic!("The alias is longer than 2048 bytes. Try using shorter names."),
+                }
+            }
+        }
+        string
+    }
+}
+
 /// A contiguous growable array type for const expressions.
 ///
 /// ## Required invariant
@@ -220,6 +239,14 @@ mod test {
         assert_eq!(ConstString::join_alias(&["a", "b"]).as_str(), "a__b");
     }
 
+    #[test]
+    fn select_alias() {
+        assert_eq!(
+            ConstString::select_alias(&["user", "__", "id", "___", "count"]).as_str(),
+            "user__id___count"
+        );
+    }
+
     #[test]
     fn error() {
         assert_eq!(
@@ -1,6 +1,10 @@
 //! Experimental trait to hide a [`FieldProxy`]s two generics behind a single one.
 
-use crate::fields::traits::{FieldEq, FieldLike, FieldOrd, FieldRegexp};
+use crate::conditions::{
+    Binary, Column, Condition, DynamicCollection, In, InOperator, StaticCollection, Ternary,
+    TernaryOperator, Unary, UnaryOperator,
+};
+use crate::fields::traits::{FieldEq, FieldLike, FieldNullable, FieldOrd, FieldRegexp};
 use crate::internal::field::{Field, FieldProxy};
 use crate::internal::relation_path::Path;
 
@@ -126,6 +130,202 @@ pub trait FieldAccess: Sized + Send + Sync + 'static {
         <FieldType!()>::field_greater_equals(self, rhs)
     }
 
+    /// Check the field to be within two bounds using `BETWEEN`
+    ///
+    /// The bounds are checked using the field's [`FieldOrd`] implementations,
+    /// so they can be a mix of values and other fields:
+    /// ```no_run
+    /// # use rorm::Model;
+    /// # use rorm::internal::field::access::FieldAccess;
+    /// # #[derive(Model)] struct Event { #[rorm(id)] id: i64, start: i64, end: i64 }
+    /// let condition = Event::F.end.between(Event::F.start, 1000);
+    /// ```
+    fn between<'rhs, Low: 'rhs, LowAny, LowArg, High: 'rhs, HighAny, HighArg>(
+        self,
+        low: Low,
+        high: High,
+    ) -> Ternary<Column<Self>, LowArg, HighArg>
+    where
+        FieldType!(): FieldOrd<'rhs, Low, LowAny, GeCond<Self> = Binary<Column<Self>, LowArg>>,
+        FieldType!(): FieldOrd<'rhs, High, HighAny, LeCond<Self> = Binary<Column<Self>, HighArg>>,
+    {
+        let Binary {
+            fst_arg: Column(access),
+            snd_arg: low,
+            ..
+        } = <FieldType!()>::field_greater_equals(self, low);
+        let Binary {
+            fst_arg,
+            snd_arg: high,
+            ..
+        } = <FieldType!()>::field_less_equals(access, high);
+        Ternary {
+            operator: TernaryOperator::Between,
+            fst_arg,
+            snd_arg: low,
+            trd_arg: high,
+        }
+    }
+
+    /// Check the field to be outside two bounds using `NOT BETWEEN`
+    ///
+    /// See [`FieldAccess::between`]
+    fn not_between<'rhs, Low: 'rhs, LowAny, LowArg, High: 'rhs, HighAny, HighArg>(
+        self,
+        low: Low,
+        high: High,
+    ) -> Ternary<Column<Self>, LowArg, HighArg>
+    where
+        FieldType!(): FieldOrd<'rhs, Low, LowAny, GeCond<Self> = Binary<Column<Self>, LowArg>>,
+        FieldType!(): FieldOrd<'rhs, High, HighAny, LeCond<Self> = Binary<Column<Self>, HighArg>>,
+    {
+        Ternary {
+            operator: TernaryOperator::NotBetween,
+            ..self.between(low, high)
+        }
+    }
+
+    /// Check the field to be contained in a list using `IN`
+    ///
+    /// Each item is converted using the field's [`FieldEq`] implementation
+    /// and bound as its own parameter.
+    /// An empty list produces a condition which is always false.
+    /// ```
+    /// use rorm::{FieldAccess, Model};
+    /// use rorm::conditions::Condition;
+    /// use rorm::db::sql::{conditional, value};
+    /// use rorm::internal::query_context::QueryContext;
+    ///
+    /// #[derive(Model)]
+    /// struct User {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    /// }
+    ///
+    /// let context = QueryContext::new();
+    ///
+    /// // Several values are passed to rorm-sql as a disjunction of the values
+    /// let condition = User::F.id.in_([1, 2, 3]);
+    /// let conditional::Condition::BinaryCondition(conditional::BinaryCondition::In(args)) =
+    ///     condition.as_sql(&context)
+    /// else {
+    ///     panic!("Expected an IN");
+    /// };
+    /// let [_, conditional::Condition::Disjunction(values)] = *args else {
+    ///     panic!("Expected a list of values");
+    /// };
+    /// assert!(matches!(
+    ///     values.as_slice(),
+    ///     [
+    ///         conditional::Condition::Value(value::Value::I64(1)),
+    ///         conditional::Condition::Value(value::Value::I64(2)),
+    ///         conditional::Condition::Value(value::Value::I64(3)),
+    ///     ]
+    /// ));
+    ///
+    /// // A single value is still a list
+    /// let condition = User::F.id.in_([1]);
+    /// let conditional::Condition::BinaryCondition(conditional::BinaryCondition::In(args)) =
+    ///     condition.as_sql(&context)
+    /// else {
+    ///     panic!("Expected an IN");
+    /// };
+    /// let [_, conditional::Condition::Disjunction(values)] = *args else {
+    ///     panic!("Expected a list of values");
+    /// };
+    /// assert!(matches!(
+    ///     values.as_slice(),
+    ///     [conditional::Condition::Value(value::Value::I64(1))]
+    /// ));
+    ///
+    /// // An empty list is replaced by a constant
+    /// let condition = User::F.id.in_(Vec::<i64>::new());
+    /// assert!(matches!(
+    ///     condition.as_sql(&context),
+    ///     conditional::Condition::Value(value::Value::Bool(false))
+    /// ));
+    /// let condition = User::F.id.not_in(Vec::<i64>::new());
+    /// assert!(matches!(
+    ///     condition.as_sql(&context),
+    ///     conditional::Condition::Value(value::Value::Bool(true))
+    /// ));
+    /// ```
+    fn in_<'rhs, Rhs: 'rhs, Any, Arg>(
+        self,
+        items: impl IntoIterator<Item = Rhs>,
+    ) -> In<Column<Self>, Arg>
+    where
+        Self: Copy,
+        FieldType!(): FieldEq<'rhs, Rhs, Any, EqCond<Self> = Binary<Column<Self>, Arg>>,
+    {
+        In {
+            operator: InOperator::In,
+            fst_arg: Column(self),
+            snd_arg: items
+                .into_iter()
+                .map(|item| <FieldType!()>::field_equals(self, item).snd_arg)
+                .collect(),
+        }
+    }
+
+    /// Check the field to not be contained in a list using `NOT IN`
+    ///
+    /// An empty list produces a condition which is always true.
+    /// See [`FieldAccess::in_`]
+    fn not_in<'rhs, Rhs: 'rhs, Any, Arg>(
+        self,
+        items: impl IntoIterator<Item = Rhs>,
+    ) -> In<Column<Self>, Arg>
+    where
+        Self: Copy,
+        FieldType!(): FieldEq<'rhs, Rhs, Any, EqCond<Self> = Binary<Column<Self>, Arg>>,
+    {
+        In {
+            operator: InOperator::NotIn,
+            ..self.in_(items)
+        }
+    }
+
+    /// Check the field to be `NULL` using `IS NULL`
+    ///
+    /// This is only available on nullable fields i.e. `Option<T>`:
+    /// ```no_run
+    /// # use rorm::Model;
+    /// # use rorm::internal::field::access::FieldAccess;
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64, deleted_at: Option<i64> }
+    /// let condition = User::F.deleted_at.is_null();
+    /// ```
+    /// ```compile_fail
+    /// # use rorm::Model;
+    /// # use rorm::internal::field::access::FieldAccess;
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64, deleted_at: Option<i64> }
+    /// let condition = User::F.id.is_null();
+    /// ```
+    #[allow(clippy::wrong_self_convention)] // named after sql's operator
+    fn is_null(self) -> Unary<Column<Self>>
+    where
+        FieldType!(): FieldNullable,
+    {
+        Unary {
+            operator: UnaryOperator::IsNull,
+            fst_arg: Column(self),
+        }
+    }
+
+    /// Check the field to not be `NULL` using `IS NOT NULL`
+    ///
+    /// See [`FieldAccess::is_null`]
+    #[allow(clippy::wrong_self_convention)] // named after sql's operator
+    fn is_not_null(self) -> Unary<Column<Self>>
+    where
+        FieldType!(): FieldNullable,
+    {
+        Unary {
+            operator: UnaryOperator::IsNotNull,
+            fst_arg: Column(self),
+        }
+    }
+
     /// Compare the field to another value using `LIKE`
     fn like<'rhs, Rhs: 'rhs, Any>(
         self,
@@ -148,6 +348,32 @@ pub trait FieldAccess: Sized + Send + Sync + 'static {
         <FieldType!()>::field_not_like(self, rhs)
     }
 
+    /// Compare the field to several patterns using `LIKE` and combine them using `OR`
+    ///
+    /// The patterns are used as is, escaping wildcards is up to the caller.
+    /// An empty list of patterns produces a condition which is always false.
+    /// ```no_run
+    /// # use rorm::Model;
+    /// # use rorm::internal::field::access::FieldAccess;
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String }
+    /// let condition = User::F.name.like_any(["foo%", "bar%"]);
+    /// ```
+    fn like_any<'rhs, Rhs: 'rhs, Any>(
+        self,
+        patterns: impl IntoIterator<Item = Rhs>,
+    ) -> DynamicCollection<<FieldType!() as FieldLike<'rhs, Rhs, Any>>::LiCond<Self>>
+    where
+        Self: Copy,
+        FieldType!(): FieldLike<'rhs, Rhs, Any>,
+    {
+        DynamicCollection::or(
+            patterns
+                .into_iter()
+                .map(|pattern| <FieldType!()>::field_like(self, pattern))
+                .collect(),
+        )
+    }
+
     /// Compare the field to another value using `>=`
     fn regexp<'rhs, Rhs: 'rhs, Any>(
         self,
@@ -175,3 +401,58 @@ impl<F: Field, P: Path> FieldAccess for FieldProxy<F, P> {
     type Field = F;
     type Path = P;
 }
+
+/// Tuple of [`FieldAccess`]es which can be compared against tuples of values at once.
+///
+/// The generic `Values` is the tuple of values, `Any` is the tuple of [`FieldEq`]'s `Any` parameters.
+///
+/// ```no_run
+/// # use rorm::Model;
+/// # use rorm::internal::field::access::FieldAccessTuple;
+/// # #[derive(Model)] struct User { #[rorm(id)] id: i64, org: i64, #[rorm(max_length = 255)] role: String }
+/// let condition = (User::F.org, User::F.role).in_([(1, "admin"), (2, "user")]);
+/// ```
+pub trait FieldAccessTuple<'rhs, Values, Any>: Copy {
+    /// Condition comparing each field to its value and joining them using `AND`
+    type EqCond: Condition<'rhs>;
+
+    /// Compare each field to its value using `==` and join them using `AND`
+    fn equals(self, values: Values) -> Self::EqCond;
+
+    /// Check the fields to match any of the value tuples
+    ///
+    /// This is expanded to an `OR` of [`FieldAccessTuple::equals`].
+    /// An empty list of values produces a condition which is always false.
+    fn in_(self, values: impl IntoIterator<Item = Values>) -> DynamicCollection<Self::EqCond> {
+        DynamicCollection::or(
+            values
+                .into_iter()
+                .map(|values| self.equals(values))
+                .collect(),
+        )
+    }
+}
+
+/// Implement [`FieldAccessTuple`] for tuples of fixed size
+macro_rules! impl_field_access_tuple {
+    ($($access:ident, $value:ident, $any:ident;)+) => {
+        #[allow(non_snake_case)] // the macro is simpler when generic variable are reused as value variables
+        impl<'rhs, $($access, $value: 'rhs, $any),+> FieldAccessTuple<'rhs, ($($value,)+), ($($any,)+)> for ($($access,)+)
+        where
+            $(
+                $access: FieldAccess + Copy,
+                <$access::Field as Field>::Type: FieldEq<'rhs, $value, $any>,
+            )+
+        {
+            type EqCond = StaticCollection<($(<<$access::Field as Field>::Type as FieldEq<'rhs, $value, $any>>::EqCond<$access>,)+)>;
+
+            fn equals(self, ($($value,)+): ($($value,)+)) -> Self::EqCond {
+                let ($($access,)+) = self;
+                StaticCollection::and(($($access.equals($value),)+))
+            }
+        }
+    };
+}
+impl_field_access_tuple!(A, RA, AnyA; B, RB, AnyB;);
+impl_field_access_tuple!(A, RA, AnyA; B, RB, AnyB; C, RC, AnyC;);
+impl_field_access_tuple!(A, RA, AnyA; B, RB, AnyB; C, RC, AnyC; D, RD, AnyD;);
@@ -0,0 +1,141 @@
+//! Support for user defined field types created by [`impl_field_type!`](crate::impl_field_type)
+
+use std::marker::PhantomData;
+
+use rorm_db::row::DecodeOwned;
+use rorm_db::Row;
+
+use crate::conditions::Value;
+use crate::crud::decoder::{Decoder, DirectDecoder};
+use crate::fields::traits::FieldType;
+use crate::internal::field::as_db_type::{get_single_imr, AsDbType};
+use crate::internal::field::decoder::FieldDecoder;
+use crate::internal::field::modifier::{MergeAnnotations, SingleColumnCheck, SingleColumnFromName};
+use crate::internal::field::{Field, FieldProxy};
+use crate::internal::hmr::annotations::Annotations;
+use crate::internal::hmr::db_type::DbType;
+use crate::internal::imr;
+use crate::internal::query_context::QueryContext;
+use crate::internal::relation_path::Path;
+use crate::{new_converting_decoder, Error};
+
+/// Conversions between a custom type and the base type it is stored as
+///
+/// This trait is implemented by [`impl_field_type!`](crate::impl_field_type)
+/// and exists to provide the `Option<T>` impls,
+/// which a user's crate is not allowed to write itself.
+pub trait CustomFieldType: AsDbType {
+    /// The type `Self` is converted to and from
+    type Base: AsDbType + DecodeOwned;
+
+    /// Convert `self` into a single [`Value`]
+    fn into_value(self) -> Value<'static>;
+
+    /// Convert `&self` into a single [`Value`]
+    fn as_value(&self) -> Value<'_>;
+
+    /// Convert the base type back into `Self`
+    fn from_base(base: Self::Base) -> Result<Self, Error>;
+}
+
+new_converting_decoder!(
+    /// [`FieldDecoder`](crate::internal::field::decoder::FieldDecoder) for a [`CustomFieldType`]
+    pub CustomDecoder<T: CustomFieldType>,
+    |value: T::Base| -> T {
+        T::from_base(value)
+    }
+);
+
+impl<T> FieldType for Option<T>
+where
+    T: CustomFieldType,
+    Option<T::Base>: DecodeOwned,
+{
+    type Columns<C> = [C; 1];
+
+    fn into_values(self) -> Self::Columns<Value<'static>> {
+        [self
+            .map(T::into_value)
+            .unwrap_or(Value::Null(<T::DbType as DbType>::NULL_TYPE))]
+    }
+
+    fn as_values(&self) -> Self::Columns<Value<'_>> {
+        [self
+            .as_ref()
+            .map(T::as_value)
+            .unwrap_or(Value::Null(<T::DbType as DbType>::NULL_TYPE))]
+    }
+
+    fn get_imr<F: Field<Type = Self>>() -> Self::Columns<imr::Field> {
+        get_single_imr::<F>(<T::DbType as DbType>::IMR)
+    }
+
+    type Decoder = OptionCustomDecoder<T>;
+
+    type AnnotationsModifier<F: Field<Type = Self>> = MergeAnnotations<Self>;
+
+    type CheckModifier<F: Field<Type = Self>> = SingleColumnCheck<T::DbType>;
+
+    type ColumnsFromName<F: Field<Type = Self>> = SingleColumnFromName;
+}
+impl<T> AsDbType for Option<T>
+where
+    T: CustomFieldType,
+    Option<T::Base>: DecodeOwned,
+    Option<T::Primitive>: DecodeOwned,
+{
+    type Primitive = Option<T::Primitive>;
+    type DbType = T::DbType;
+
+    const IMPLICIT: Option<Annotations> = {
+        let mut annos = if let Some(annos) = T::IMPLICIT {
+            annos
+        } else {
+            Annotations::empty()
+        };
+        annos.nullable = true;
+        Some(annos)
+    };
+}
+/// [`FieldDecoder`] for an `Option<impl CustomFieldType>`
+///
+/// This is written by hand instead of using `new_converting_decoder!`,
+/// because its impls require `Option<T::Base>: DecodeOwned`.
+pub struct OptionCustomDecoder<T: CustomFieldType> {
+    decoder: DirectDecoder<Option<T::Base>>,
+}
+impl<T> Decoder for OptionCustomDecoder<T>
+where
+    T: CustomFieldType,
+    Option<T::Base>: DecodeOwned,
+{
+    type Result = Option<T>;
+
+    fn by_name(&self, row: &Row) -> Result<Self::Result, Error> {
+        self.decoder.by_name(row)?.map(T::from_base).transpose()
+    }
+
+    fn by_index(&self, row: &Row) -> Result<Self::Result, Error> {
+        self.decoder.by_index(row)?.map(T::from_base).transpose()
+    }
+}
+impl<T> FieldDecoder for OptionCustomDecoder<T>
+where
+    T: CustomFieldType,
+    Option<T::Base>: DecodeOwned,
+{
+    fn new<F, P>(ctx: &mut QueryContext, _: FieldProxy<F, P>) -> Self
+    where
+        F: Field<Type = Self::Result>,
+        P: Path,
+    {
+        let (index, column) = ctx.select_field::<F, P>();
+        Self {
+            decoder: DirectDecoder {
+                result: PhantomData,
+                column,
+                index,
+            },
+        }
+    }
+}
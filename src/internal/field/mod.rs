@@ -49,6 +49,7 @@ use crate::model::{ConstNew, GetField, Model};
 
 pub mod access;
 pub mod as_db_type;
+pub mod custom_type;
 pub mod decoder;
 pub mod foreign_model;
 pub mod modifier;
@@ -190,6 +191,32 @@ impl<F: Field, P> FieldProxy<F, P> {
         FieldProxy::new()
     }
 }
+impl<F: Field, P: Path> FieldProxy<F, P> {
+    /// Get the alias of the table the field is accessed through
+    ///
+    /// The alias is derived from the whole relation path,
+    /// so every join gets its own alias even when a model is joined onto itself:
+    /// ```
+    /// # use rorm::Model;
+    /// # use rorm::fields::types::ForeignModel;
+    /// # use rorm::internal::field::FieldProxy;
+    /// #[derive(Model)]
+    /// struct Employee {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     boss: Option<ForeignModel<Employee>>,
+    /// }
+    ///
+    /// assert_ne!(
+    ///     FieldProxy::alias(Employee::F.boss.id),
+    ///     FieldProxy::alias(Employee::F.boss.boss.id),
+    /// );
+    /// ```
+    pub const fn alias(_field: Self) -> &'static str {
+        P::ALIAS
+    }
+}
 impl<F: Field, P> FieldProxy<F, P> {
     /// Get the names of the columns which store the field
     pub const fn columns(_field: Self) -> <F::Type as FieldType>::Columns<&'static str> {
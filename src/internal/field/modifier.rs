@@ -8,6 +8,7 @@ use crate::internal::field::as_db_type::AsDbType;
 use crate::internal::field::Field;
 use crate::internal::hmr::annotations::Annotations;
 use crate::internal::hmr::db_type::DbType;
+use crate::internal::imr;
 use crate::Model;
 
 /// Trait used in [`FieldType`] to allow types to modify their fields' annotations.
@@ -81,7 +82,35 @@ impl<F: Field> CheckModifier<F> for NoCheck {
 /// [`CheckModifier`] which:
 /// - requires `F::EFFECTIVE_ANNOTATIONS` to be `Some`
 /// - ensures all annotations required by `D` are set
+/// - ensures `SetNull` is only used on nullable fields
 /// - runs the shared linter from `rorm-declaration`
+///
+/// ```
+/// # use rorm::Model;
+/// # use rorm::fields::types::ForeignModel;
+/// # #[derive(Model)] struct User { #[rorm(id)] id: i64 }
+/// #[derive(Model)]
+/// struct Post {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     #[rorm(on_delete = "SetNull")]
+///     author: Option<ForeignModel<User>>,
+/// }
+/// ```
+/// ```compile_fail
+/// # use rorm::Model;
+/// # use rorm::fields::types::ForeignModel;
+/// # #[derive(Model)] struct User { #[rorm(id)] id: i64 }
+/// #[derive(Model)]
+/// struct Post {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     #[rorm(on_delete = "SetNull")]
+///     author: ForeignModel<User>,
+/// }
+/// ```
 pub struct SingleColumnCheck<D: DbType>(pub PhantomData<D>);
 
 impl<D: DbType, F: Field> CheckModifier<F> for SingleColumnCheck<D> {
@@ -103,6 +132,16 @@ impl<D: DbType, F: Field> CheckModifier<F> for SingleColumnCheck<D> {
                 }
             }
 
+            // Can the column actually be set to NULL?
+            if !annotations.nullable
+                && (matches!(annotations.on_delete, Some(imr::ReferentialAction::SetNull))
+                    || matches!(annotations.on_update, Some(imr::ReferentialAction::SetNull)))
+            {
+                break 'result Err(ConstString::error(&[
+                    "SetNull as on_delete or on_update requires the field to be nullable",
+                ]));
+            }
+
             // Run the annotations lint shared with rorm-cli
             let annotations = annotations.as_lint();
             if let Err(err) = annotations.check() {
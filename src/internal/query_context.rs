@@ -4,9 +4,10 @@ use std::borrow::Cow;
 use std::collections::HashSet;
 
 use rorm_db::sql::conditional::{BinaryCondition, Condition};
+use rorm_db::sql::join_table::JoinType;
 use rorm_db::sql::value::Value;
 
-use crate::aggregate::AggregationFunc;
+use crate::aggregate::{AggregatedColumn, AggregationFunc};
 use crate::internal::field::Field;
 use crate::internal::relation_path::{JoinAlias, Path, PathImpl, PathStep};
 use crate::Model;
@@ -18,9 +19,49 @@ type PathId = std::any::TypeId;
 ///
 /// Since rorm-db borrows all of its parameters, there has to be someone who own it.
 /// This struct owns all the implicit data required to query something i.e. join and alias information.
-#[derive(Debug, Default)]
+///
+/// Cloning a context keeps its joins including their [`JoinType`]s:
+/// ```
+/// use rorm::Model;
+/// use rorm::db::sql::join_table::JoinType;
+/// use rorm::fields::types::ForeignModel;
+/// use rorm::internal::field::{Field, FieldProxy};
+/// use rorm::internal::query_context::QueryContext;
+/// use rorm::internal::relation_path::{Path, PathStep};
+///
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+/// }
+///
+/// #[derive(Model)]
+/// struct Post {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     author: Option<ForeignModel<User>>,
+/// }
+///
+/// fn left_join<F: Field, P: Path>(context: &mut QueryContext, _relation: FieldProxy<F, P>)
+/// where
+///     PathStep<F, P>: Path,
+/// {
+///     context.set_join_type::<PathStep<F, P>>(JoinType::LeftJoin);
+/// }
+///
+/// let mut context = QueryContext::new();
+/// left_join(&mut context, Post::F.author);
+/// let clone = context.clone();
+/// let joins = clone.get_joins();
+/// assert_eq!(joins.len(), 1);
+/// assert_eq!(joins[0].join_alias, FieldProxy::alias(Post::F.author.id));
+/// assert!(matches!(joins[0].join_type, JoinType::LeftJoin));
+/// ```
+#[derive(Debug, Default, Clone)]
 pub struct QueryContext {
     handled_paths: HashSet<PathId>,
+    /// Joins in the order they have to be applied, i.e. every join comes after the ones it depends on
     joins: Vec<Join>,
     selects: Vec<Select>,
 }
@@ -46,12 +87,7 @@ impl QueryContext {
     /// Add a field to aggregate returning its index and alias
     pub fn select_aggregation<A: AggregationFunc, F: Field, P: Path>(&mut self) -> (usize, String) {
         P::add_to_context(self);
-        let alias = format!(
-            "{path}__{field}___{func}",
-            path = P::ALIAS,
-            field = F::NAME,
-            func = A::NAME,
-        );
+        let alias = AggregatedColumn::<A, F, P>::ALIAS.to_string();
         self.selects.push(Select {
             table_name: Cow::Borrowed(P::ALIAS),
             column_name: F::NAME,
@@ -61,7 +97,88 @@ impl QueryContext {
         (self.selects.len() - 1, alias)
     }
 
+    /// Is there a select using `alias`?
+    pub fn is_selected(&self, alias: &str) -> bool {
+        self.selects
+            .iter()
+            .any(|select| select.select_alias == alias)
+    }
+
+    /// Change the type of the join required by a path, adding the path if necessary
+    ///
+    /// All joins default to [`JoinType::Join`].
+    pub fn set_join_type<P: Path>(&mut self, join_type: JoinType) {
+        P::add_to_context(self);
+        for join in &mut self.joins {
+            match join {
+                Join::Static {
+                    join_alias,
+                    join_type: old_join_type,
+                    ..
+                } => {
+                    if *join_alias == P::ALIAS {
+                        *old_join_type = join_type;
+                    }
+                }
+            }
+        }
+    }
+
     /// Create a vector borrowing the joins in rorm_db's format which can be passed to it as slice.
+    ///
+    /// The joins are returned in the order they have been added.
+    /// Since a [`PathStep`] adds its parent path before itself,
+    /// a join's condition only ever references tables which have been joined before it:
+    /// ```
+    /// use rorm::{and, field, FieldAccess, Model};
+    /// use rorm::conditions::Condition;
+    /// use rorm::fields::types::{BackRef, ForeignModel};
+    /// use rorm::internal::field::FieldProxy;
+    /// use rorm::internal::query_context::QueryContext;
+    ///
+    /// #[derive(Model)]
+    /// struct User {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     #[rorm(max_length = 255)]
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(Model)]
+    /// struct TestGame {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     players: BackRef<field!(TestGameUser::F.game)>,
+    /// }
+    ///
+    /// #[derive(Model)]
+    /// struct TestGameUser {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     game: ForeignModel<TestGame>,
+    ///
+    ///     user: ForeignModel<User>,
+    /// }
+    ///
+    /// let condition = and!(
+    ///     TestGame::F.players.user.name.equals("alice"),
+    ///     TestGame::F.players.id.equals(1),
+    /// );
+    /// let expected = [
+    ///     FieldProxy::alias(TestGame::F.players.id),
+    ///     FieldProxy::alias(TestGame::F.players.user.id),
+    /// ];
+    /// for _ in 0..100 {
+    ///     let mut context = QueryContext::new();
+    ///     condition.add_to_context(&mut context);
+    ///     let joins = context.get_joins();
+    ///     let aliases: Vec<_> = joins.iter().map(|join| join.join_alias).collect();
+    ///     assert_eq!(aliases, expected);
+    /// }
+    /// ```
     pub fn get_joins(&self) -> Vec<rorm_db::database::JoinTable> {
         self.joins.iter().map(Join::as_db_format).collect()
     }
@@ -164,26 +281,29 @@ enum TempJoinData {
     },
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Join {
     Static {
         table_name: &'static str,
         join_alias: &'static str,
         join_condition: Condition<'static>,
+        join_type: JoinType,
     },
 }
 
 impl Join {
     fn as_db_format(&self) -> rorm_db::database::JoinTable {
-        let (table_name, join_alias, join_condition): (&str, &str, &Condition) = match self {
-            Join::Static {
-                table_name,
-                join_alias,
-                join_condition,
-            } => (table_name, join_alias, join_condition),
-        };
+        let (table_name, join_alias, join_condition, join_type): (&str, &str, &Condition, _) =
+            match self {
+                Join::Static {
+                    table_name,
+                    join_alias,
+                    join_condition,
+                    join_type,
+                } => (table_name, join_alias, join_condition, *join_type),
+            };
         rorm_db::database::JoinTable {
-            join_type: rorm_db::sql::join_table::JoinType::Join,
+            join_type,
             table_name,
             join_alias,
             join_condition,
@@ -210,6 +330,7 @@ impl From<TempJoinData> for Join {
                         column_name: column_b,
                     }),
                 ]))),
+                join_type: JoinType::Join,
             },
         }
     }
@@ -146,6 +146,37 @@ pub trait PathImpl<RawType> {
     type ResolvedRelatedField: Field;
 
     /// The two field joined on.
+    ///
+    /// Each field is given as `[table alias, column name]`.
+    /// For foreign models the related side is the field the foreign model points to,
+    /// which doesn't have to be the related model's primary key:
+    /// ```
+    /// # use rorm::{field, Model};
+    /// # use rorm::fields::types::ForeignModelByField;
+    /// # use rorm::internal::relation_path::{JoinAlias, PathImpl, PathStep};
+    /// #[derive(Model)]
+    /// struct User {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     #[rorm(max_length = 255, unique)]
+    ///     email: String,
+    /// }
+    ///
+    /// #[derive(Model)]
+    /// struct Post {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     author: ForeignModelByField<field!(User::F.email)>,
+    /// }
+    ///
+    /// type Author = PathStep<field!(Post::F.author), Post>;
+    /// assert_eq!(
+    ///     <Author as PathImpl<_>>::JOIN_FIELDS,
+    ///     [[Author::ALIAS, "email"], ["post", "author"]],
+    /// );
+    /// ```
     const JOIN_FIELDS: [[&'static str; 2]; 2];
 
     /// Add all joins required to use this path to the query context
@@ -43,8 +43,9 @@ pub mod prelude {
 
     pub use crate::field;
     pub use crate::fields::types::{BackRef, ForeignModel, ForeignModelByField};
-    pub use crate::internal::field::access::FieldAccess;
+    pub use crate::internal::field::access::{FieldAccess, FieldAccessTuple};
     pub use crate::model::{Model, Patch};
+    pub use crate::transaction::TransactionExt;
 }
 
 pub mod aggregate;
@@ -53,6 +54,7 @@ pub mod crud;
 pub mod fields;
 pub mod internal;
 pub mod model;
+pub mod transaction;
 
 /// This slice is populated by the [`Model`] macro with all models.
 ///
@@ -60,17 +62,79 @@ pub mod model;
 #[allow(non_camel_case_types)]
 #[linkme::distributed_slice]
 #[doc(hidden)]
-pub static MODELS: [fn() -> imr::Model] = [..];
+pub static MODELS: [(Option<&'static str>, fn() -> imr::Model)] = [..];
 
-/// Write all models in the Intermediate Model Representation to a [writer](std::io::Write).
+/// Write all models without a [migration group](model::Model::MIGRATION_GROUP)
+/// in the Intermediate Model Representation to a [writer](std::io::Write).
+///
+/// Models with a migration group are written by [`write_models_of_group`] instead,
+/// so every model ends up in exactly one migration set:
+/// ```
+/// use rorm::imr::InternalModelFormat;
+/// use rorm::{migration_groups, write_models, write_models_of_group, Model};
+///
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+/// }
+///
+/// #[derive(Model)]
+/// #[rorm(migration_group = "audit")]
+/// struct AuditLog {
+///     #[rorm(id)]
+///     id: i64,
+/// }
+///
+/// fn tables(json: Vec<u8>) -> Vec<String> {
+///     let imf: InternalModelFormat = serde_json::from_slice(&json).unwrap();
+///     imf.models.into_iter().map(|model| model.name).collect()
+/// }
+///
+/// let mut default = Vec::new();
+/// write_models(&mut default).unwrap();
+/// assert_eq!(tables(default), ["user"]);
+///
+/// let mut audit = Vec::new();
+/// write_models_of_group(&mut audit, Some("audit")).unwrap();
+/// assert_eq!(tables(audit), ["auditlog"]);
+///
+/// assert_eq!(migration_groups(), ["audit"]);
+/// ```
 pub fn write_models(writer: &mut impl std::io::Write) -> Result<(), String> {
+    write_models_of_group(writer, None)
+}
+
+/// Write the models of a single [migration group](model::Model::MIGRATION_GROUP)
+/// in the Intermediate Model Representation to a [writer](std::io::Write).
+///
+/// Use `None` to write the models without an explicit group.
+pub fn write_models_of_group(
+    writer: &mut impl std::io::Write,
+    group: Option<&str>,
+) -> Result<(), String> {
     let imf = imr::InternalModelFormat {
-        models: MODELS.iter().map(|func| func()).collect(),
+        models: MODELS
+            .iter()
+            .filter(|(model_group, _)| *model_group == group)
+            .map(|(_, func)| func())
+            .collect(),
     };
     serde_json::to_writer(writer, &imf).map_err(|err| err.to_string())
 }
 
-/// Prints all models in the Intermediate Model Representation to stdout.
+/// List all [migration groups](model::Model::MIGRATION_GROUP) used by at least one model
+///
+/// The default group is not included.
+pub fn migration_groups() -> Vec<&'static str> {
+    let mut groups: Vec<_> = MODELS.iter().filter_map(|(group, _)| *group).collect();
+    groups.sort_unstable();
+    groups.dedup();
+    groups
+}
+
+/// Prints all models without a [migration group](model::Model::MIGRATION_GROUP)
+/// in the Intermediate Model Representation to stdout.
 /// This should be used as a main function to produce the file for the migrator.
 ///
 /// See also [`rorm_main`]
@@ -125,6 +189,7 @@ macro_rules! field {
 /// When you build with the `rorm-main` feature enabled this attribute will replace your main function.
 /// The new main function will simply write all your defined models to `./.models.json`
 /// to be further process by the migrator.
+/// Models with a [migration group](model::Model::MIGRATION_GROUP) are written to `./.models.<group>.json` instead.
 ///
 /// Make sure you have added the feature `rorm-main` to your crate i.e. put the following in your `Cargo.toml`:
 /// ```toml
@@ -150,6 +215,48 @@ pub use rorm_macro::rorm_main;
 ///     Other,
 /// }
 /// ```
+///
+/// By default a variant is stored using its name.
+/// Use `#[rorm(rename_all = "..")]` on the enum to apply a case convention
+/// (the same ones serde supports, i.e. `"lowercase"`, `"snake_case"`, `"SCREAMING-KEBAB-CASE"`, etc.)
+/// or `#[rorm(rename = "..")]` on a single variant to choose its value explicitly:
+/// ```
+/// use rorm::DbEnum;
+/// use rorm::fields::traits::FieldType;
+/// use rorm::conditions::Value;
+///
+/// #[derive(DbEnum)]
+/// #[rorm(rename_all = "snake_case")]
+/// pub enum State {
+///     InProgress,
+///     Done,
+///     #[rorm(rename = "n/a")]
+///     NotApplicable,
+/// }
+///
+/// assert!(matches!(State::InProgress.into_values(), [Value::Choice(value)] if value == "in_progress"));
+/// assert!(matches!(State::NotApplicable.into_values(), [Value::Choice(value)] if value == "n/a"));
+/// ```
+///
+/// To store the enum as integer instead of string, specify the integer type using `#[rorm(as = "..")]`
+/// (one of `"i16"`, `"i32"` or `"i64"`) and give every variant its value using `#[rorm(value = ..)]`.
+/// Decoding an integer which doesn't belong to any variant results in an error.
+/// ```
+/// use rorm::DbEnum;
+/// use rorm::fields::traits::FieldType;
+/// use rorm::conditions::Value;
+///
+/// #[derive(DbEnum)]
+/// #[rorm(as = "i16")]
+/// pub enum Status {
+///     #[rorm(value = 0)]
+///     Pending,
+///     #[rorm(value = 1)]
+///     Done,
+/// }
+///
+/// assert!(matches!(Status::Done.into_values(), [Value::I16(1)]));
+/// ```
 pub use rorm_macro::DbEnum;
 /// ```no_run
 /// use rorm::Model;
@@ -172,6 +279,31 @@ pub use rorm_macro::DbEnum;
 ///     age: i16,
 /// }
 /// ```
+///
+/// Besides `#[rorm(default = ..)]` which sets a `DEFAULT` in the database,
+/// `#[rorm(default_fn = "..")]` names a function which is called by rorm to produce a field's value,
+/// whenever a [`Patch`](model::Patch) which doesn't contain this field is inserted.
+/// Since the database doesn't know about this function, it won't be applied to rows inserted by other means.
+/// ```
+/// use rorm::Model;
+/// use rorm::model::Model as _;
+///
+/// fn new_token() -> String {
+///     "random".to_string()
+/// }
+///
+/// #[derive(Model)]
+/// struct Session {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     #[rorm(max_length = 255, default_fn = "new_token")]
+///     token: String,
+/// }
+///
+/// assert_eq!(Session::DEFAULT_FN_COLUMNS, ["token"]);
+/// assert!(Session::default_fn_value("token").is_some());
+/// ```
 pub use rorm_macro::Model;
 /// ```no_run
 /// use rorm::{Model, Patch};
@@ -2,11 +2,15 @@
 
 use std::marker::PhantomData;
 
+use rorm_db::row::DecodeOwned;
+use rorm_db::sql::join_table::JoinType;
+use rorm_db::sql::ordering::OrderByEntry;
 use rorm_declaration::imr;
 
 use crate::conditions::{Binary, BinaryOperator, Column, Value};
-use crate::crud::decoder::Decoder;
+use crate::crud::decoder::{Decoder, DirectDecoder, OptionalDecoder};
 use crate::crud::selector::Selector;
+use crate::internal::field::as_db_type::AsDbType;
 use crate::internal::field::{Field, FieldProxy, SingleColumnField};
 use crate::internal::query_context::QueryContext;
 use crate::internal::relation_path::Path;
@@ -71,6 +75,7 @@ impl<Ptch: Patch, Pth: Path> Selector for PatchSelector<Ptch, Pth> {
     type Model = Pth::Origin;
     type Decoder = Ptch::Decoder;
     const INSERT_COMPATIBLE: bool = Pth::IS_ORIGIN;
+    const DEFAULT_ORDERING_COMPATIBLE: bool = true;
 
     fn select(self, ctx: &mut QueryContext) -> Self::Decoder {
         Pth::add_to_context(ctx);
@@ -78,6 +83,112 @@ impl<Ptch: Patch, Pth: Path> Selector for PatchSelector<Ptch, Pth> {
     }
 }
 
+/// [`Selector`] selecting a [`Patch`] from a table which might not have been joined
+///
+/// The selector [left joins](crate::crud::query::QueryBuilder::left_join) the patch's table,
+/// so rows without a related row are kept and their patch is decoded as `None`
+/// (detected by the table's primary key being `NULL`).
+/// Only the path's last join is changed, joins "behind" it have to be left joined separately.
+///
+/// It is used by [`query!`](crate::query) when selecting a relation `as Option<Patch>`:
+/// ```no_run
+/// # use rorm::{field, Model, Database, query};
+/// # use rorm::fields::types::{BackRef, ForeignModel};
+/// # #[derive(Model)] struct User { #[rorm(id)] id: i64, posts: BackRef<field!(Post::F.author)> }
+/// # #[derive(Model)] struct Post { #[rorm(id)] id: i64, #[rorm(max_length = 255)] title: String, author: ForeignModel<User> }
+/// # async fn func(db: &Database) {
+/// // Users without posts are returned once with `None`
+/// let users_and_posts: Vec<(i64, Option<Post>)> = query!(db, (User::F.id, User::F.posts as Option<Post>))
+///     .all()
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+///
+/// ```
+/// use rorm::{field, Model};
+/// use rorm::crud::selector::Selector;
+/// use rorm::db::sql::join_table::JoinType;
+/// use rorm::fields::types::{BackRef, ForeignModel};
+/// use rorm::internal::field::{Field, FieldProxy};
+/// use rorm::internal::query_context::QueryContext;
+/// use rorm::internal::relation_path::{Path, PathStep};
+/// use rorm::model::{OptionalPatchSelector, Patch};
+///
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     posts: BackRef<field!(Post::F.author)>,
+/// }
+///
+/// #[derive(Model)]
+/// struct Post {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     author: ForeignModel<User>,
+/// }
+///
+/// fn select_optional<Ptch: Patch, F: Field, P: Path>(
+///     context: &mut QueryContext,
+///     _relation: FieldProxy<F, P>,
+/// ) where
+///     PathStep<F, P>: Path,
+///     OptionalPatchSelector<Ptch, PathStep<F, P>>: Selector,
+/// {
+///     OptionalPatchSelector::<Ptch, PathStep<F, P>>::new().select(context);
+/// }
+///
+/// let mut context = QueryContext::new();
+/// select_optional::<Post, _, _>(&mut context, User::F.posts);
+/// let joins = context.get_joins();
+/// assert_eq!(joins.len(), 1);
+/// assert!(matches!(joins[0].join_type, JoinType::LeftJoin));
+/// ```
+pub struct OptionalPatchSelector<Ptch: Patch, Pth = <Ptch as Patch>::Model>(
+    PhantomData<(Ptch, Pth)>,
+);
+
+impl<Ptch: Patch, Pth> OptionalPatchSelector<Ptch, Pth> {
+    /// construct a new instance
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Ptch: Patch, Pth: Path> Default for OptionalPatchSelector<Ptch, Pth> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Ptch: Patch, Pth: Path> Selector for OptionalPatchSelector<Ptch, Pth>
+where
+    PrimaryKey<Ptch::Model>: AsDbType,
+    Option<<PrimaryKey<Ptch::Model> as AsDbType>::Primitive>: DecodeOwned,
+{
+    type Result = Option<Ptch>;
+    type Model = Pth::Origin;
+    type Decoder = OptionalDecoder<Ptch::Decoder, <PrimaryKey<Ptch::Model> as AsDbType>::Primitive>;
+    const INSERT_COMPATIBLE: bool = false;
+    const DEFAULT_ORDERING_COMPATIBLE: bool = true;
+
+    fn select(self, ctx: &mut QueryContext) -> Self::Decoder {
+        ctx.set_join_type::<Pth>(JoinType::LeftJoin);
+        let (index, column) = ctx.select_field::<<Ptch::Model as Model>::Primary, Pth>();
+        OptionalDecoder {
+            decoder: Ptch::select::<Pth>(ctx),
+            check: DirectDecoder {
+                result: PhantomData,
+                column,
+                index,
+            },
+        }
+    }
+}
+
 /// The [Condition](crate::conditions::Condition) type returned by [Identifiable::as_condition]
 pub type PatchAsCondition<'a, P> = Binary<
     Column<FieldProxy<<<P as Patch>::Model as Model>::Primary, <P as Patch>::Model>>,
@@ -107,6 +218,74 @@ pub trait Model: Patch<Model = Self> {
     /// The model's table name
     const TABLE: &'static str;
 
+    /// Ordering applied by [`query!`](crate::query) if no other ordering has been set
+    ///
+    /// It is set using `#[rorm(order_by("field", "-other_field"))]` where a `-` means descending.
+    ///
+    /// It isn't applied to queries selecting aggregations,
+    /// because they can't be ordered by columns which are neither aggregated nor grouped:
+    /// ```
+    /// # use rorm::{Model, FieldAccess};
+    /// # use rorm::crud::selector::Selector;
+    /// # use rorm::db::sql::ordering::Ordering;
+    /// # use rorm::model::PatchSelector;
+    /// #[derive(Model)]
+    /// #[rorm(order_by("-created", "name"))]
+    /// struct Article {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     #[rorm(max_length = 255)]
+    ///     name: String,
+    ///
+    ///     created: i64,
+    /// }
+    ///
+    /// let [created, name] = Article::DEFAULT_ORDERING else { panic!() };
+    /// assert!(matches!(created.ordering, Ordering::Desc));
+    /// assert_eq!(created.column_name, "created");
+    /// assert!(matches!(name.ordering, Ordering::Asc));
+    /// assert_eq!(name.column_name, "name");
+    ///
+    /// fn default_ordering_compatible<S: Selector>(_: S) -> bool {
+    ///     S::DEFAULT_ORDERING_COMPATIBLE
+    /// }
+    /// assert!(default_ordering_compatible(PatchSelector::<Article>::new()));
+    /// assert!(default_ordering_compatible((Article::F.id, Article::F.name)));
+    /// assert!(!default_ordering_compatible((Article::F.id.count(),)));
+    /// assert!(!default_ordering_compatible((Article::F.name, Article::F.created.max())));
+    /// ```
+    const DEFAULT_ORDERING: &'static [OrderByEntry<'static>] = &[];
+
+    /// The migration group the model belongs to
+    ///
+    /// It is set using `#[rorm(migration_group = "...")]`.
+    /// Models without one belong to the default group (`None`).
+    /// See [`write_models_of_group`](crate::write_models_of_group).
+    const MIGRATION_GROUP: Option<&'static str> = None;
+
+    /// Columns whose value is computed in rust, if an inserted patch doesn't contain them
+    ///
+    /// They are set using `#[rorm(default_fn = "...")]`,
+    /// `#[rorm(auto_create_time)]` or `#[rorm(auto_update_time)]`.
+    /// Unlike `#[rorm(default = ...)]`, this is not a `DEFAULT` known to the database.
+    /// Therefore, it only applies to inserts performed by rorm.
+    const DEFAULT_FN_COLUMNS: &'static [&'static str] = &[];
+
+    /// Computes the value for a column listed in [`Model::DEFAULT_FN_COLUMNS`]
+    ///
+    /// Returns `None` for any other column.
+    fn default_fn_value(column: &str) -> Option<Value<'static>> {
+        let _ = column;
+        None
+    }
+
+    /// Columns set to the current time by every [`update!`](crate::update), unless set explicitly
+    ///
+    /// They are set using `#[rorm(auto_update_time)]`
+    /// and their values are computed by [`Model::default_fn_value`].
+    const AUTO_UPDATE_TIME_COLUMNS: &'static [&'static str] = &[];
+
     /// Returns the model's intermediate representation
     ///
     /// As library user you probably won't need this. You might want to look at [`write_models`].
@@ -234,10 +413,57 @@ pub trait UpdateField<F: Field<Model = Self>>: Model {
     ) -> T;
 }
 
+/// The type of a model's primary key
+pub type PrimaryKey<M> = <<M as Model>::Primary as Field>::Type;
+
 /// A patch which contains its model's primary key.
 pub trait Identifiable: Patch {
     /// Get a reference to the primary key
-    fn get_primary_key(&self) -> &<<Self::Model as Model>::Primary as Field>::Type;
+    fn get_primary_key(&self) -> &PrimaryKey<Self::Model>;
+
+    /// Get the primary key as [`Value`]
+    ///
+    /// This allows generic code to work with any model's primary key
+    /// without knowing its concrete type:
+    /// ```
+    /// use rorm::conditions::Value;
+    /// use rorm::model::{Identifiable, PrimaryKey};
+    /// use rorm::Model;
+    ///
+    /// #[derive(Model)]
+    /// struct User {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    /// }
+    ///
+    /// #[derive(Model)]
+    /// struct Country {
+    ///     #[rorm(primary_key, max_length = 2)]
+    ///     code: String,
+    /// }
+    ///
+    /// fn cache_key(instance: &impl Identifiable) -> Option<String> {
+    ///     match instance.primary_key_value() {
+    ///         Value::I64(id) => Some(id.to_string()),
+    ///         Value::String(id) => Some(id.into_owned()),
+    ///         _ => None,
+    ///     }
+    /// }
+    ///
+    /// let user = User { id: 42 };
+    /// let id: &PrimaryKey<User> = user.get_primary_key();
+    /// assert_eq!(*id, 42);
+    /// assert!(matches!(user.primary_key_value(), Value::I64(42)));
+    /// assert_eq!(cache_key(&user).as_deref(), Some("42"));
+    ///
+    /// let country = Country { code: "DE".to_string() };
+    /// let code: &PrimaryKey<Country> = country.get_primary_key();
+    /// assert_eq!(code, "DE");
+    /// assert_eq!(cache_key(&country).as_deref(), Some("DE"));
+    /// ```
+    fn primary_key_value(&self) -> Value<'_> {
+        <Self::Model as Model>::Primary::type_as_value(self.get_primary_key())
+    }
 
     /// Build a [Condition](crate::conditions::Condition)
     /// which only applies to this instance by comparing the primary key.
@@ -245,13 +471,13 @@ pub trait Identifiable: Patch {
         Binary {
             operator: BinaryOperator::Equals,
             fst_arg: Column(FieldProxy::new()),
-            snd_arg: <Self::Model as Model>::Primary::type_as_value(self.get_primary_key()),
+            snd_arg: self.primary_key_value(),
         }
     }
 }
 
 impl<M: Model, P: Patch<Model = M> + GetField<M::Primary>> Identifiable for P {
-    fn get_primary_key(&self) -> &<M::Primary as Field>::Type {
+    fn get_primary_key(&self) -> &PrimaryKey<M> {
         <Self as GetField<M::Primary>>::borrow_field(self)
     }
 }
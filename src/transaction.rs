@@ -0,0 +1,123 @@
+//! Helper for running code inside a transaction
+
+use std::panic::AssertUnwindSafe;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use rorm_db::transaction::Transaction;
+use rorm_db::{Database, Error};
+
+use crate::sealed;
+
+/// Extension trait adding [`transaction`](TransactionExt::transaction) to [`Database`]
+///
+/// [`Database`] is defined in `rorm-db`, so this method can't be implemented on it directly.
+pub trait TransactionExt {
+    sealed!(trait);
+
+    /// Run a closure inside a transaction
+    ///
+    /// This is the method version of [`with_transaction`]:
+    /// the transaction is committed if the closure returns `Ok`
+    /// and rolled back if it returns `Err` or panics.
+    ///
+    /// ```no_run
+    /// # use rorm::{Model, Database, FieldAccess, insert, query, Error};
+    /// # use rorm::transaction::TransactionExt;
+    /// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String }
+    /// enum SignupError {
+    ///     Database(Error),
+    ///     NameTaken,
+    /// }
+    ///
+    /// impl From<Error> for SignupError {
+    ///     fn from(error: Error) -> Self {
+    ///         Self::Database(error)
+    ///     }
+    /// }
+    ///
+    /// # async fn func(db: &Database) -> Result<(), Error> {
+    /// let result = db
+    ///     .transaction(|tx| {
+    ///         Box::pin(async move {
+    ///             let user = User { id: 1, name: "Alice".to_string() };
+    ///             insert!(&mut *tx, User).single(&user).await?;
+    ///
+    ///             // Returning `Err` rolls back the insert above
+    ///             Err::<(), _>(SignupError::NameTaken)
+    ///         })
+    ///     })
+    ///     .await;
+    /// assert!(matches!(result, Err(SignupError::NameTaken)));
+    ///
+    /// let alice = query!(db, User).condition(User::F.id.equals(1)).optional().await?;
+    /// assert!(alice.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transaction<'db, T, E, F>(&'db self, f: F) -> BoxFuture<'db, Result<T, E>>
+    where
+        F: for<'tx> FnOnce(&'tx mut Transaction) -> BoxFuture<'tx, Result<T, E>> + Send + 'db,
+        T: Send + 'db,
+        E: From<Error> + Send + 'db;
+}
+impl TransactionExt for Database {
+    sealed!(impl);
+
+    fn transaction<'db, T, E, F>(&'db self, f: F) -> BoxFuture<'db, Result<T, E>>
+    where
+        F: for<'tx> FnOnce(&'tx mut Transaction) -> BoxFuture<'tx, Result<T, E>> + Send + 'db,
+        T: Send + 'db,
+        E: From<Error> + Send + 'db,
+    {
+        Box::pin(with_transaction(self, f))
+    }
+}
+
+/// Run a closure inside a transaction
+///
+/// The transaction is committed if the closure returns `Ok`
+/// and rolled back if it returns `Err` or panics.
+///
+/// The closure has to return a boxed future because it borrows the transaction.
+/// Its error type has to be constructable from rorm's [`Error`]
+/// to report errors while starting, committing or rolling back the transaction.
+///
+/// ```no_run
+/// # use rorm::{Model, Database, insert, Error};
+/// # use rorm::transaction::with_transaction;
+/// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String }
+/// # async fn func(db: &Database) -> Result<(), Error> {
+/// let id = with_transaction(db, |tx| {
+///     Box::pin(async move {
+///         let user = User { id: 1, name: "Alice".to_string() };
+///         insert!(&mut *tx, User).single(&user).await?;
+///         Ok::<_, Error>(user.id)
+///     })
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_transaction<T, E, F>(db: &Database, f: F) -> Result<T, E>
+where
+    F: for<'tx> FnOnce(&'tx mut Transaction) -> BoxFuture<'tx, Result<T, E>>,
+    E: From<Error>,
+{
+    let mut tx = db.start_transaction().await?;
+    match AssertUnwindSafe(f(&mut tx)).catch_unwind().await {
+        Ok(Ok(value)) => {
+            tx.commit().await?;
+            Ok(value)
+        }
+        Ok(Err(error)) => {
+            tx.rollback().await?;
+            Err(error)
+        }
+        Err(panic) => {
+            // The panic takes precedence over any error while rolling back
+            let _ = tx.rollback().await;
+            std::panic::resume_unwind(panic)
+        }
+    }
+}